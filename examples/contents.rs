@@ -82,13 +82,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("{}", desc);
             }
 
-            if let Some(content) = &result.content {
+            if let Some(content_str) = result.content_as_string() {
                 println!("\n📋 Content:");
-                let content_str = match content {
-                    serde_json::Value::String(s) => s.clone(),
-                    _ => serde_json::to_string_pretty(&content)?,
-                };
-
                 let preview = if content_str.len() > 500 {
                     format!("{}...", &content_str[..500])
                 } else {
@@ -1,36 +1,31 @@
 //! Debug example to see raw API response
 
-use std::env;
+use valyu::{DeepSearchRequest, ValyuClient};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenvy::dotenv().ok();
 
-    let api_key = env::var("VALYU_API_KEY")?;
+    let client = ValyuClient::builder()
+        .api_key(std::env::var("VALYU_API_KEY")?)
+        .capture_raw(true)
+        .build()?;
 
-    // Make raw request
-    let client = reqwest::Client::new();
-    let response = client
-        .post("https://api.valyu.ai/v1/deepsearch")
-        .header("Content-Type", "application/json")
-        .header("x-api-key", &api_key)
-        .json(&serde_json::json!({
-            "query": "test query",
-            "max_num_results": 2
-        }))
-        .send()
-        .await?;
-
-    println!("Status: {}", response.status());
-
-    let text = response.text().await?;
-    println!("\nRaw response:");
-    println!("{}", text);
-
-    // Try to parse as generic JSON
-    let json: serde_json::Value = serde_json::from_str(&text)?;
-    println!("\nParsed JSON (pretty):");
+    // Fetch the untyped body, keeping the SDK's auth and retry handling
+    let request = DeepSearchRequest::new("test query").with_max_results(2);
+    let json = client.deep_search_raw(&request).await?;
+    println!("Raw response (pretty):");
     println!("{}", serde_json::to_string_pretty(&json)?);
 
+    // Typed calls also record their raw body when capture_raw is enabled
+    let response = client.deep_search(&request).await?;
+    println!("\nTyped response has {} results", response.result_count());
+    if let Some(raw) = client.last_raw_response() {
+        println!(
+            "Captured body has {} top-level fields",
+            raw.as_object().map_or(0, |o| o.len())
+        );
+    }
+
     Ok(())
 }
@@ -19,10 +19,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Example 1: Create a basic research task
     println!("1. Creating a basic research task...");
-    let request =
-        DeepResearchCreateRequest::new("What are the key differences between RAG and fine-tuning for LLMs?")
-            .with_mode(DeepResearchMode::Lite)
-            .with_output_formats(vec!["markdown".to_string()]);
+    let request = DeepResearchCreateRequest::new(
+        "What are the key differences between RAG and fine-tuning for LLMs?",
+    )
+    .with_mode(DeepResearchMode::Standard)
+    .with_output_formats(vec!["markdown".to_string()]);
 
     let task = client.deepresearch_create(&request).await?;
 
@@ -37,11 +38,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .as_ref()
         .expect("Task ID should be present");
 
+    let mode = DeepResearchMode::Standard;
     let result = client
         .deepresearch_wait(
             task_id,
-            5,   // Poll every 5 seconds
-            900, // Timeout after 15 minutes
+            mode.default_poll_interval_secs(),
+            mode.default_timeout_secs(),
         )
         .await?;
 
@@ -49,13 +51,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   Status: {:?}", result.status);
 
     // Display output
-    if let Some(output) = &result.output {
+    if let Some(report) = result.output_markdown() {
         println!("\n=== Research Output ===");
-        let output_str = output.to_string();
-        if output_str.len() > 500 {
-            println!("{}...\n", &output_str[..500]);
+        if report.chars().count() > 500 {
+            let preview: String = report.chars().take(500).collect();
+            println!("{}...\n", preview);
         } else {
-            println!("{}\n", output_str);
+            println!("{}\n", report);
         }
     }
 
@@ -0,0 +1,71 @@
+//! How the client sends its API key
+
+/// How the API key is sent with each request
+///
+/// # Example
+///
+/// ```
+/// use valyu::{AuthMode, ValyuClient};
+///
+/// // Behind a proxy that only forwards standard headers
+/// let client = ValyuClient::builder()
+///     .api_key("your-api-key")
+///     .auth_mode(AuthMode::Bearer)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AuthMode {
+    /// Send the key in the `x-api-key` header (default)
+    #[default]
+    ApiKeyHeader,
+    /// Send the key as `Authorization: Bearer <key>`, for gateways that strip
+    /// non-standard headers
+    Bearer,
+}
+
+impl AuthMode {
+    /// Name of the header carrying the key
+    pub(crate) fn header_name(self) -> &'static str {
+        match self {
+            AuthMode::ApiKeyHeader => "x-api-key",
+            AuthMode::Bearer => "authorization",
+        }
+    }
+
+    /// Add the key to a request
+    pub(crate) fn apply(
+        self,
+        request: reqwest::RequestBuilder,
+        api_key: &str,
+    ) -> reqwest::RequestBuilder {
+        match self {
+            AuthMode::ApiKeyHeader => request.header(self.header_name(), api_key),
+            AuthMode::Bearer => request.bearer_auth(api_key),
+        }
+    }
+}
+
+/// Mask an API key for `Debug` output, keeping only its last four characters
+///
+/// Keys too short to reveal a suffix safely are masked entirely.
+pub(crate) fn redact_api_key(api_key: &str) -> String {
+    let chars: Vec<char> = api_key.chars().collect();
+    if chars.len() <= 8 {
+        return "***".to_string();
+    }
+    let suffix: String = chars[chars.len() - 4..].iter().collect();
+    format!("...{}", suffix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_api_key() {
+        assert_eq!(redact_api_key("sk-1234567890abcd"), "...abcd");
+        assert_eq!(redact_api_key("short"), "***");
+        assert_eq!(redact_api_key(""), "***");
+    }
+}
@@ -0,0 +1,500 @@
+//! Blocking client for synchronous code
+//!
+//! Enabled with the `blocking` feature. [`ValyuClient`] mirrors the async
+//! [`crate::ValyuClient`] and shares its request, response, and error types;
+//! each call runs the async client to completion on a private runtime, so
+//! retries, timeouts, and validation behave identically.
+//!
+//! This wraps the async client rather than using `reqwest::blocking` so that
+//! the transport layer (including the mock transport used in tests), retry
+//! policy, request validation, and status-code mapping exist only once.
+//! Porting them to `reqwest::blocking` would duplicate every endpoint.
+//!
+//! Every async method has a blocking version except
+//! [`deepresearch_stream`](crate::ValyuClient::deepresearch_stream), which
+//! returns a `Stream`; poll with [`ValyuClient::deepresearch_status`] or wait
+//! with [`ValyuClient::deepresearch_wait_with_progress`] instead. Methods that
+//! don't perform a request, such as
+//! [`last_raw_response`](crate::ValyuClient::last_raw_response), are reached
+//! through [`ValyuClient::as_async`].
+//!
+//! Like `reqwest::blocking`, this client must not be used from within an async
+//! runtime, since blocking there would panic.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use valyu::blocking::ValyuClient;
+//!
+//! fn main() -> Result<(), valyu::ValyuError> {
+//!     let client = ValyuClient::new("your-api-key");
+//!     let response = client.search("quantum computing")?;
+//!     println!("Found {} results", response.result_count());
+//!     Ok(())
+//! }
+//! ```
+
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::error::Result;
+use crate::types::{
+    AnswerRequest, AnswerResponse, BudgetedSearchResult, ContentResult, ContentsRequest,
+    ContentsResponse, DeepResearchCreateRequest, DeepResearchCreateResponse,
+    DeepResearchListResponse, DeepResearchOperationResponse, DeepResearchProgress,
+    DeepResearchStatusResponse, DeepSearchRequest, DeepSearchResponse, DeliverableResult,
+    SearchResult,
+};
+
+/// Synchronous client for interacting with the Valyu API
+///
+/// Cloning is cheap; clones share the underlying runtime and connection pool.
+#[derive(Debug, Clone)]
+pub struct ValyuClient {
+    inner: crate::ValyuClient,
+    runtime: Arc<tokio::runtime::Runtime>,
+}
+
+impl ValyuClient {
+    /// Create a new blocking client with an API key
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal runtime cannot be started; use
+    /// [`from_async`](Self::from_async) to handle that error instead.
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self::from_async(crate::ValyuClient::new(api_key))
+            .expect("failed to start the blocking client runtime")
+    }
+
+    /// Create a new blocking client with a custom base URL
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal runtime cannot be started.
+    pub fn with_base_url(api_key: impl Into<String>, base_url: impl Into<String>) -> Self {
+        Self::from_async(crate::ValyuClient::with_base_url(api_key, base_url))
+            .expect("failed to start the blocking client runtime")
+    }
+
+    /// Blocking version of [`crate::ValyuClient::with_api_key`], sharing the
+    /// runtime as well
+    pub fn with_api_key(&self, api_key: impl Into<String>) -> Self {
+        Self {
+            inner: self.inner.with_api_key(api_key),
+            runtime: Arc::clone(&self.runtime),
+        }
+    }
+
+    /// Create a new blocking client from the `VALYU_API_KEY` and
+    /// `VALYU_BASE_URL` environment variables
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::MissingApiKey`](crate::ValyuError::MissingApiKey)
+    /// if `VALYU_API_KEY` is unset or empty.
+    pub fn from_env() -> Result<Self> {
+        Self::from_async(crate::ValyuClient::from_env()?)
+    }
+
+    /// Wrap a configured async client, e.g. one created with
+    /// [`ValyuClient::builder`](crate::ValyuClient::builder)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{RetryPolicy, ValyuClient};
+    ///
+    /// let client = ValyuClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .retry_policy(RetryPolicy::new(3))
+    ///     .build()?;
+    /// let client = valyu::blocking::ValyuClient::from_async(client)?;
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::Io`](crate::ValyuError::Io) if the internal
+    /// runtime cannot be started.
+    pub fn from_async(client: crate::ValyuClient) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client this blocking client wraps
+    pub fn as_async(&self) -> &crate::ValyuClient {
+        &self.inner
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Blocking version of [`crate::ValyuClient::verify_api_key`]
+    pub fn verify_api_key(&self) -> Result<()> {
+        self.block_on(self.inner.verify_api_key())
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deep_search`]
+    pub fn deep_search(&self, request: impl Into<DeepSearchRequest>) -> Result<DeepSearchResponse> {
+        self.block_on(self.inner.deep_search(request))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deep_search_raw`]
+    pub fn deep_search_raw(&self, request: &DeepSearchRequest) -> Result<serde_json::Value> {
+        self.block_on(self.inner.deep_search_raw(request))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::search`]
+    pub fn search(&self, query: impl Into<String>) -> Result<DeepSearchResponse> {
+        self.block_on(self.inner.search(query))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::search_batch`]
+    ///
+    /// Requests still run `concurrency` at a time; the call blocks until all
+    /// of them finish.
+    pub fn search_batch(
+        &self,
+        requests: &[DeepSearchRequest],
+        concurrency: usize,
+    ) -> Vec<Result<DeepSearchResponse>> {
+        self.block_on(self.inner.search_batch(requests, concurrency))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::search_batch_try`]
+    pub fn search_batch_try(
+        &self,
+        requests: &[DeepSearchRequest],
+        concurrency: usize,
+    ) -> Result<Vec<DeepSearchResponse>> {
+        self.block_on(self.inner.search_batch_try(requests, concurrency))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::search_until_budget`]
+    pub fn search_until_budget(
+        &self,
+        requests: impl Iterator<Item = DeepSearchRequest>,
+        budget_usd: f64,
+    ) -> Result<BudgetedSearchResult> {
+        self.block_on(self.inner.search_until_budget(requests, budget_usd))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::contents`]
+    pub fn contents(&self, request: &ContentsRequest) -> Result<ContentsResponse> {
+        self.block_on(self.inner.contents(request))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::contents_raw`]
+    pub fn contents_raw(&self, request: &ContentsRequest) -> Result<serde_json::Value> {
+        self.block_on(self.inner.contents_raw(request))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::content`]
+    pub fn content(&self, url: impl Into<String>) -> Result<ContentResult> {
+        self.block_on(self.inner.content(url))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::fetch_content`]
+    pub fn fetch_content(&self, result: &SearchResult) -> Result<ContentResult> {
+        self.block_on(self.inner.fetch_content(result))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::download_images`]
+    pub fn download_images(
+        &self,
+        result: &ContentResult,
+        dir: impl AsRef<Path>,
+    ) -> Result<Vec<(String, Result<PathBuf>)>> {
+        self.block_on(self.inner.download_images(result, dir))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::download_pdf`]
+    pub fn download_pdf(
+        &self,
+        status: &DeepResearchStatusResponse,
+        path: impl AsRef<Path>,
+    ) -> Result<PathBuf> {
+        self.block_on(self.inner.download_pdf(status, path))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::answer`]
+    pub fn answer(&self, request: &AnswerRequest) -> Result<AnswerResponse> {
+        self.block_on(self.inner.answer(request))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::answer_raw`]
+    pub fn answer_raw(&self, request: &AnswerRequest) -> Result<serde_json::Value> {
+        self.block_on(self.inner.answer_raw(request))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::ask`]
+    pub fn ask(&self, query: impl Into<String>) -> Result<AnswerResponse> {
+        self.block_on(self.inner.ask(query))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::ask_text`]
+    pub fn ask_text(&self, query: impl Into<String>) -> Result<String> {
+        self.block_on(self.inner.ask_text(query))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_create`]
+    pub fn deepresearch_create(
+        &self,
+        request: &DeepResearchCreateRequest,
+    ) -> Result<DeepResearchCreateResponse> {
+        self.block_on(self.inner.deepresearch_create(request))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::research`]
+    pub fn research(&self, query: impl Into<String>) -> Result<DeepResearchCreateResponse> {
+        self.block_on(self.inner.research(query))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_follow_up`]
+    pub fn deepresearch_follow_up(
+        &self,
+        previous_id: impl Into<String>,
+        input: impl Into<String>,
+    ) -> Result<DeepResearchCreateResponse> {
+        self.block_on(self.inner.deepresearch_follow_up(previous_id, input))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_status`]
+    pub fn deepresearch_status(
+        &self,
+        task_id: impl AsRef<str>,
+    ) -> Result<DeepResearchStatusResponse> {
+        self.block_on(self.inner.deepresearch_status(task_id))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_status_raw`]
+    pub fn deepresearch_status_raw(&self, task_id: impl AsRef<str>) -> Result<serde_json::Value> {
+        self.block_on(self.inner.deepresearch_status_raw(task_id))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_wait`]
+    pub fn deepresearch_wait(
+        &self,
+        task_id: impl AsRef<str>,
+        poll_interval_secs: u64,
+        max_wait_secs: u64,
+    ) -> Result<DeepResearchStatusResponse> {
+        self.block_on(
+            self.inner
+                .deepresearch_wait(task_id, poll_interval_secs, max_wait_secs),
+        )
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_wait_with_progress`]
+    ///
+    /// `on_progress` runs on the calling thread between polls.
+    pub fn deepresearch_wait_with_progress(
+        &self,
+        task_id: impl AsRef<str>,
+        poll_interval_secs: u64,
+        max_wait_secs: u64,
+        on_progress: impl FnMut(&DeepResearchProgress),
+    ) -> Result<DeepResearchStatusResponse> {
+        self.block_on(self.inner.deepresearch_wait_with_progress(
+            task_id,
+            poll_interval_secs,
+            max_wait_secs,
+            on_progress,
+        ))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_wait_with_jitter`]
+    pub fn deepresearch_wait_with_jitter(
+        &self,
+        task_id: impl AsRef<str>,
+        poll_interval_secs: u64,
+        jitter_fraction: f64,
+        max_wait_secs: u64,
+    ) -> Result<DeepResearchStatusResponse> {
+        self.block_on(self.inner.deepresearch_wait_with_jitter(
+            task_id,
+            poll_interval_secs,
+            jitter_fraction,
+            max_wait_secs,
+        ))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_run`]
+    pub fn deepresearch_run(
+        &self,
+        request: &DeepResearchCreateRequest,
+        poll_interval_secs: u64,
+    ) -> Result<DeepResearchStatusResponse> {
+        self.block_on(self.inner.deepresearch_run(request, poll_interval_secs))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_deliverables`]
+    pub fn deepresearch_deliverables(
+        &self,
+        request: &DeepResearchCreateRequest,
+        poll_interval_secs: u64,
+    ) -> Result<Vec<DeliverableResult>> {
+        self.block_on(
+            self.inner
+                .deepresearch_deliverables(request, poll_interval_secs),
+        )
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_wait_cancellable`]
+    ///
+    /// Cancel the token from another thread to stop waiting.
+    pub fn deepresearch_wait_cancellable(
+        &self,
+        task_id: impl AsRef<str>,
+        poll_interval_secs: u64,
+        max_wait_secs: u64,
+        cancel: CancellationToken,
+    ) -> Result<DeepResearchStatusResponse> {
+        self.block_on(self.inner.deepresearch_wait_cancellable(
+            task_id,
+            poll_interval_secs,
+            max_wait_secs,
+            cancel,
+        ))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_list`]
+    pub fn deepresearch_list(
+        &self,
+        api_key_id: impl AsRef<str>,
+        limit: Option<u32>,
+    ) -> Result<DeepResearchListResponse> {
+        self.block_on(self.inner.deepresearch_list(api_key_id, limit))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_list_with`]
+    pub fn deepresearch_list_with(
+        &self,
+        params: &[(&str, &str)],
+    ) -> Result<DeepResearchListResponse> {
+        self.block_on(self.inner.deepresearch_list_with(params))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_update`]
+    pub fn deepresearch_update(
+        &self,
+        task_id: impl AsRef<str>,
+        instruction: impl Into<String>,
+    ) -> Result<DeepResearchOperationResponse> {
+        self.block_on(self.inner.deepresearch_update(task_id, instruction))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_update_with`]
+    pub fn deepresearch_update_with(
+        &self,
+        task_id: impl AsRef<str>,
+        body: serde_json::Value,
+    ) -> Result<DeepResearchOperationResponse> {
+        self.block_on(self.inner.deepresearch_update_with(task_id, body))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_cancel`]
+    pub fn deepresearch_cancel(
+        &self,
+        task_id: impl AsRef<str>,
+    ) -> Result<DeepResearchOperationResponse> {
+        self.block_on(self.inner.deepresearch_cancel(task_id))
+    }
+
+    /// Blocking version of [`crate::ValyuClient::deepresearch_delete`]
+    pub fn deepresearch_delete(
+        &self,
+        task_id: impl AsRef<str>,
+    ) -> Result<DeepResearchOperationResponse> {
+        self.block_on(self.inner.deepresearch_delete(task_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{mock_client, MockResponse, MockTransport};
+    use crate::{DeepResearchStatus, ValyuError};
+
+    #[test]
+    fn test_blocking_calls() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "results": [{ "title": "a" }] }),
+            ))
+            .with_response(MockResponse::new(401, "{}"));
+        let client = ValyuClient::from_async(mock_client(transport)).unwrap();
+
+        assert_eq!(client.search("test").unwrap().result_count(), 1);
+        assert!(matches!(
+            client.search("test"),
+            Err(ValyuError::InvalidApiKey)
+        ));
+    }
+
+    #[test]
+    fn test_blocking_content_and_answer() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({
+                    "success": true,
+                    "results": [{ "url": "https://example.com", "content": "full text" }]
+                }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "contents": "42", "data_type": "unstructured" }),
+            ));
+        let client = ValyuClient::from_async(mock_client(transport.clone())).unwrap();
+
+        let content = client.content("https://example.com").unwrap();
+        assert_eq!(content.content_as_string().as_deref(), Some("full text"));
+        assert_eq!(client.ask_text("question").unwrap(), "42");
+        assert!(transport.requests()[1].url.ends_with("/answer"));
+    }
+
+    #[test]
+    fn test_blocking_deepresearch_wait_with_progress() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({
+                    "success": true,
+                    "status": "running",
+                    "progress": { "current_step": 1, "total_steps": 2 }
+                }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({
+                    "success": true,
+                    "status": "completed",
+                    "progress": { "current_step": 2, "total_steps": 2 }
+                }),
+            ));
+        let client = ValyuClient::from_async(mock_client(transport)).unwrap();
+
+        let mut steps = Vec::new();
+        let result = client
+            .deepresearch_wait_with_progress("task-id", 0, 60, |progress| {
+                steps.push(progress.current_step);
+            })
+            .unwrap();
+
+        assert_eq!(result.status, Some(DeepResearchStatus::Completed));
+        assert_eq!(steps, vec![1, 2]);
+    }
+}
@@ -0,0 +1,541 @@
+//! Builder for configuring a [`ValyuClient`]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use tokio::sync::Semaphore;
+
+use crate::auth::{redact_api_key, AuthMode};
+use crate::client::{ValyuClient, API_BASE_URL, DEFAULT_CONNECT_TIMEOUT, DEFAULT_TIMEOUT};
+use crate::endpoint::Endpoint;
+use crate::error::{Result, ValyuError};
+use crate::retry::RetryPolicy;
+use crate::transport::{ReqwestTransport, Transport};
+
+/// Builder for [`ValyuClient`]
+///
+/// Created with [`ValyuClient::builder`]. All settings are optional except the
+/// API key.
+///
+/// # Example
+///
+/// ```
+/// use valyu::{RetryPolicy, ValyuClient};
+/// use std::time::Duration;
+///
+/// let client = ValyuClient::builder()
+///     .api_key("your-api-key")
+///     .timeout(Duration::from_secs(30))
+///     .retry_policy(RetryPolicy::new(3))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct ValyuClientBuilder {
+    api_key: Option<String>,
+    auth_mode: AuthMode,
+    base_url: Option<String>,
+    endpoint_bases: HashMap<Endpoint, String>,
+    http_client: Option<reqwest::Client>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    max_concurrency: Option<usize>,
+    default_headers: HeaderMap,
+    invalid_header: Option<String>,
+    transport: Option<Arc<dyn Transport>>,
+    capture_raw: bool,
+    error_context: bool,
+    error_context_raw_query: bool,
+    default_tool_call: Option<bool>,
+    #[cfg(feature = "compression")]
+    compression: Option<bool>,
+}
+
+impl std::fmt::Debug for ValyuClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValyuClientBuilder")
+            .field("api_key", &self.api_key.as_deref().map(redact_api_key))
+            .field("auth_mode", &self.auth_mode)
+            .field("base_url", &self.base_url)
+            .field("endpoint_bases", &self.endpoint_bases)
+            .field("timeout", &self.timeout)
+            .field("retry_policy", &self.retry_policy)
+            .field("max_concurrency", &self.max_concurrency)
+            .field("default_headers", &self.default_headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Headers the client sets itself, which default headers may not override
+///
+/// The header carrying the API key, which depends on the [`AuthMode`], is
+/// reserved too.
+const RESERVED_HEADERS: &[&str] = &["x-api-key", "content-type"];
+
+impl ValyuClientBuilder {
+    /// Create a new builder with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the API key used to authenticate requests (required)
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Choose how the API key is sent (default: the `x-api-key` header)
+    ///
+    /// Use [`AuthMode::Bearer`] to send `Authorization: Bearer <key>` instead,
+    /// e.g. behind a gateway that strips non-standard headers.
+    pub fn auth_mode(mut self, mode: AuthMode) -> Self {
+        self.auth_mode = mode;
+        self
+    }
+
+    /// Set a custom base URL (default: `https://api.valyu.ai/v1`)
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Send one group of endpoints to its own base URL
+    ///
+    /// Endpoints without an override use [`base_url`](Self::base_url). For
+    /// example, route `/deepresearch` through a different host than
+    /// `/deepsearch` without constructing a second client.
+    pub fn endpoint_base(mut self, endpoint: Endpoint, base_url: impl Into<String>) -> Self {
+        self.endpoint_bases.insert(endpoint, base_url.into());
+        self
+    }
+
+    /// Use a preconfigured reqwest client, e.g. for proxy settings
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Set a timeout applied to every request (default: 60 seconds)
+    ///
+    /// This is applied per request, so it also works together with a custom
+    /// [`http_client`](Self::http_client). With a custom client and no timeout
+    /// set here, the client's own timeout is used instead of the default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long to wait for a connection to be established (default: 10
+    /// seconds)
+    ///
+    /// Only applies to the client the SDK builds itself, not a custom
+    /// [`http_client`](Self::http_client).
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum number of idle connections kept open per host
+    ///
+    /// Raise this for services issuing many concurrent requests, so bursts
+    /// reuse connections instead of opening new ones. This only applies to the
+    /// client the SDK builds itself, not a custom
+    /// [`http_client`](Self::http_client).
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Set how long idle connections are kept open (reqwest's default: 90 seconds)
+    ///
+    /// Only applies to the client the SDK builds itself.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the policy for retrying transient failures (default: no retries)
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Limit how many requests the client has in flight at once (default: unlimited)
+    ///
+    /// The limit is shared by every method and by clones of the client. Calls
+    /// beyond it wait for a free slot instead of failing. A slot is held until
+    /// the response body has been read, including streamed file downloads.
+    /// Each retry attempt takes its own slot, and the slot is released while
+    /// waiting out the retry backoff, so retries queue behind other calls
+    /// rather than holding capacity while idle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::ValyuClient;
+    ///
+    /// let client = ValyuClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .max_concurrency(4)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn max_concurrency(mut self, limit: usize) -> Self {
+        self.max_concurrency = Some(limit);
+        self
+    }
+
+    /// Add a header sent with every request, e.g. a tenant or request ID header
+    ///
+    /// The SDK's own `x-api-key` and `Content-Type` headers cannot be
+    /// overridden. Invalid or reserved headers are reported by
+    /// [`build`](Self::build).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::ValyuClient;
+    ///
+    /// let client = ValyuClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .default_header("X-Tenant-Id", "acme")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn default_header(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        let (name, value) = (name.as_ref(), value.as_ref());
+        match (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                self.default_headers.insert(name, value);
+            }
+            _ => {
+                self.invalid_header
+                    .get_or_insert_with(|| format!("invalid default header: {}", name));
+            }
+        }
+        self
+    }
+
+    /// Add several headers sent with every request
+    ///
+    /// Headers are merged with any added by [`default_header`](Self::default_header),
+    /// replacing earlier values with the same name.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        for (name, value) in headers {
+            if let Some(name) = name {
+                self.default_headers.insert(name, value);
+            }
+        }
+        self
+    }
+
+    /// Enable or disable compressed responses (requires the `compression` feature)
+    ///
+    /// When enabled, the client advertises gzip, brotli, and deflate support
+    /// and transparently decompresses response bodies. This mostly pays off for
+    /// large payloads such as DeepResearch status responses with embedded
+    /// markdown output, which typically shrink several times over the wire.
+    ///
+    /// Compression is on by default with the feature enabled. The setting
+    /// only applies to the client the SDK builds itself; configure a custom
+    /// [`http_client`](Self::http_client) directly instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::ValyuClient;
+    ///
+    /// let client = ValyuClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .compression(true)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "compression")]
+    pub fn compression(mut self, enabled: bool) -> Self {
+        self.compression = Some(enabled);
+        self
+    }
+
+    /// Keep the raw JSON body of the most recent response (default: off)
+    ///
+    /// Retrieve it with [`ValyuClient::last_raw_response`] to inspect fields
+    /// the typed responses don't model. Each response body is cloned once
+    /// while this is enabled.
+    pub fn capture_raw(mut self, enabled: bool) -> Self {
+        self.capture_raw = enabled;
+        self
+    }
+
+    /// Set `is_tool_call` on DeepSearch requests that don't set it themselves
+    ///
+    /// The API treats requests as AI agent tool calls unless told otherwise.
+    /// Pass `false` for apps that show search results to people, so every
+    /// search (including [`ValyuClient::search`]) gets results meant for
+    /// humans.
+    pub fn default_tool_call(mut self, is_tool_call: bool) -> Self {
+        self.default_tool_call = Some(is_tool_call);
+        self
+    }
+
+    /// Wrap errors from API calls in [`ValyuError::WithContext`], recording
+    /// the endpoint and the length of the query (default: `false`)
+    ///
+    /// Useful for logging which call failed without threading the request
+    /// through your own code. Match on [`ValyuError::root`] to handle
+    /// specific errors when this is enabled. The query itself is redacted
+    /// unless [`error_context_raw_query`](Self::error_context_raw_query) is
+    /// also enabled.
+    pub fn error_context(mut self, enabled: bool) -> Self {
+        self.error_context = enabled;
+        self
+    }
+
+    /// Record the start of the query text in [`ValyuError::WithContext`]
+    /// instead of only its length (default: `false`)
+    ///
+    /// Queries can contain sensitive data, so only enable this where errors
+    /// are logged somewhere it is safe to keep them. Has no effect unless
+    /// [`error_context`](Self::error_context) is enabled.
+    pub fn error_context_raw_query(mut self, enabled: bool) -> Self {
+        self.error_context_raw_query = enabled;
+        self
+    }
+
+    /// Send requests through a custom [`Transport`] instead of reqwest
+    ///
+    /// Requests are still built with the configured
+    /// [`http_client`](Self::http_client), but sent by the transport. This is
+    /// mainly useful for tests; see `MockTransport` in the `test_util` module
+    /// (behind the `test-util` feature).
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Build the client
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::MissingApiKey`] if no API key was provided,
+    /// [`ValyuError::InvalidRequest`] if a default header is invalid or would
+    /// override a header the SDK sets itself or `max_concurrency` is 0, or [`ValyuError::RequestFailed`]
+    /// if the HTTP client cannot be created.
+    pub fn build(self) -> Result<ValyuClient> {
+        let api_key = self
+            .api_key
+            .filter(|key| !key.is_empty())
+            .ok_or(ValyuError::MissingApiKey)?;
+
+        if let Some(message) = self.invalid_header {
+            return Err(ValyuError::InvalidRequest(message));
+        }
+
+        if self.max_concurrency == Some(0) {
+            return Err(ValyuError::InvalidRequest(
+                "max_concurrency must be at least 1".to_string(),
+            ));
+        }
+
+        if let Some(reserved) = RESERVED_HEADERS
+            .iter()
+            .chain([&self.auth_mode.header_name()])
+            .find(|name| self.default_headers.contains_key(**name))
+        {
+            return Err(ValyuError::InvalidRequest(format!(
+                "the {} header is set by the client and cannot be overridden",
+                reserved
+            )));
+        }
+
+        // A custom client keeps its own timeout unless one is set explicitly
+        let timeout = match (self.timeout, &self.http_client) {
+            (Some(timeout), _) => Some(timeout),
+            (None, Some(_)) => None,
+            (None, None) => Some(DEFAULT_TIMEOUT),
+        };
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder()
+                    .connect_timeout(self.connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT));
+
+                #[cfg(feature = "compression")]
+                {
+                    let enabled = self.compression.unwrap_or(true);
+                    builder = builder.gzip(enabled).brotli(enabled).deflate(enabled);
+                }
+
+                if let Some(max) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(max);
+                }
+                if let Some(timeout) = self.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(timeout);
+                }
+
+                builder.build()?
+            }
+        };
+        let transport = self
+            .transport
+            .unwrap_or_else(|| Arc::new(ReqwestTransport::new(client.clone())));
+
+        Ok(ValyuClient {
+            api_key,
+            auth_mode: self.auth_mode,
+            client,
+            base_url: self.base_url.unwrap_or_else(|| API_BASE_URL.to_string()),
+            endpoint_bases: self.endpoint_bases,
+            timeout,
+            retry_policy: self.retry_policy,
+            concurrency_limit: self
+                .max_concurrency
+                .map(|limit| Arc::new(Semaphore::new(limit))),
+            default_headers: self.default_headers,
+            transport,
+            last_raw_response: self.capture_raw.then(Default::default),
+            error_context: self.error_context,
+            error_context_raw_query: self.error_context_raw_query,
+            default_tool_call: self.default_tool_call,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_requires_api_key() {
+        let result = ValyuClientBuilder::new().build();
+        assert!(matches!(result, Err(ValyuError::MissingApiKey)));
+
+        let result = ValyuClientBuilder::new().api_key("").build();
+        assert!(matches!(result, Err(ValyuError::MissingApiKey)));
+    }
+
+    #[test]
+    fn test_build_with_settings() {
+        let client = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .base_url("https://custom.url")
+            .timeout(Duration::from_secs(5))
+            .retry_policy(RetryPolicy::new(2))
+            .build()
+            .unwrap();
+
+        assert_eq!(client.api_key, "test-key");
+        assert_eq!(client.base_url, "https://custom.url");
+        assert_eq!(client.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(client.retry_policy.max_retries, 2);
+    }
+
+    #[test]
+    fn test_default_headers() {
+        let mut extra = HeaderMap::new();
+        extra.insert("x-request-id", HeaderValue::from_static("abc"));
+
+        let client = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .default_header("X-Tenant-Id", "acme")
+            .default_headers(extra)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.default_headers["x-tenant-id"], "acme");
+        assert_eq!(client.default_headers["x-request-id"], "abc");
+    }
+
+    #[test]
+    fn test_default_headers_cannot_override_reserved() {
+        let result = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .default_header("X-API-Key", "other-key")
+            .build();
+        assert!(matches!(result, Err(ValyuError::InvalidRequest(_))));
+
+        let result = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .default_header("bad header", "value")
+            .build();
+        assert!(matches!(result, Err(ValyuError::InvalidRequest(_))));
+    }
+
+    #[test]
+    fn test_bearer_auth_reserves_authorization() {
+        let result = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .auth_mode(AuthMode::Bearer)
+            .default_header("Authorization", "Basic abc")
+            .build();
+        assert!(matches!(result, Err(ValyuError::InvalidRequest(_))));
+
+        let result = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .default_header("Authorization", "Basic abc")
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_build_defaults() {
+        let client = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.base_url, API_BASE_URL);
+        assert_eq!(client.timeout, Some(DEFAULT_TIMEOUT));
+        assert_eq!(client.retry_policy, RetryPolicy::default());
+    }
+
+    #[test]
+    fn test_custom_http_client_keeps_its_timeout() {
+        let client = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .http_client(reqwest::Client::new())
+            .build()
+            .unwrap();
+        assert_eq!(client.timeout, None);
+
+        let client = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .http_client(reqwest::Client::new())
+            .timeout(Duration::from_secs(5))
+            .connect_timeout(Duration::from_secs(2))
+            .build()
+            .unwrap();
+        assert_eq!(client.timeout, Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_build_with_pool_settings() {
+        let client = ValyuClientBuilder::new()
+            .api_key("test-key")
+            .pool_max_idle_per_host(64)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .build();
+        assert!(client.is_ok());
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_build_with_compression() {
+        for enabled in [true, false] {
+            let client = ValyuClientBuilder::new()
+                .api_key("test-key")
+                .compression(enabled)
+                .build();
+            assert!(client.is_ok());
+        }
+    }
+}
@@ -1,19 +1,215 @@
 //! Client for interacting with the Valyu API
 
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+
+use crate::auth::{redact_api_key, AuthMode};
+use crate::builder::ValyuClientBuilder;
+use crate::endpoint::Endpoint;
 use crate::error::{Result, ValyuError};
+use crate::retry::RetryPolicy;
+use crate::transport::{ReqwestTransport, Transport};
+#[cfg(feature = "tracing")]
+use crate::types::Cost;
 use crate::types::{
-    AnswerRequest, AnswerResponse, ContentsRequest, ContentsResponse, DeepSearchRequest,
-    DeepSearchResponse,
+    AnswerRequest,
+    AnswerResponse,
+    BudgetedSearchResult,
+    ContentResult,
+    ContentsRequest,
+    ContentsResponse,
     // DeepResearch API
-    DeepResearchCreateRequest, DeepResearchCreateResponse, DeepResearchListResponse,
-    DeepResearchOperationResponse, DeepResearchStatus, DeepResearchStatusResponse,
+    DeepResearchCreateRequest,
+    DeepResearchCreateResponse,
+    DeepResearchListResponse,
+    DeepResearchOperationResponse,
+    DeepResearchProgress,
+    DeepResearchStatus,
+    DeepResearchStatusResponse,
+    DeepSearchRequest,
+    DeepSearchResponse,
+    DeliverableResult,
+    DeliverableStatus,
+    RateLimitInfo,
+    SearchResult,
 };
 
 /// Base URL for the Valyu API
-const API_BASE_URL: &str = "https://api.valyu.ai/v1";
+pub(crate) const API_BASE_URL: &str = "https://api.valyu.ai/v1";
+
+/// Total time allowed for a request unless configured otherwise
+pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Time allowed to establish a connection unless configured otherwise
+pub(crate) const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Environment variable holding the API key
+const API_KEY_ENV: &str = "VALYU_API_KEY";
+
+/// Environment variable overriding the base URL
+const BASE_URL_ENV: &str = "VALYU_BASE_URL";
+
+/// Number of images [`ValyuClient::download_images`] fetches at once
+const IMAGE_DOWNLOAD_CONCURRENCY: usize = 4;
+
+/// Deserialize a JSON response body, turning `success: false` payloads into
+/// [`ValyuError::ApiError`] with the API's message and error code
+///
+/// `error` and `code` are read independently and leniently, so an
+/// unexpected type in one (e.g. a numeric `code`) doesn't hide the other.
+fn parse_api_value<T: DeserializeOwned>(value: serde_json::Value) -> Result<T> {
+    if value.get("success") == Some(&serde_json::Value::Bool(false)) {
+        return Err(ValyuError::ApiError {
+            message: value
+                .get("error")
+                .and_then(json_text)
+                .unwrap_or_else(|| "API request was not successful".to_string()),
+            code: value.get("code").and_then(json_text),
+        });
+    }
+
+    parse_value(value)
+}
+
+/// Text of a JSON field, converting non-string values (e.g. a numeric code)
+/// to their JSON representation; `null` counts as missing
+fn json_text(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(text) => Some(text.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Deserialize a JSON response body
+fn parse_value<T: DeserializeOwned>(value: serde_json::Value) -> Result<T> {
+    T::deserialize(value)
+        .map_err(|e| ValyuError::ParseError(format!("Failed to parse API response: {}", e)))
+}
+
+/// Pick a file name for each image URL from the last segment of its path
+///
+/// Unsafe characters are replaced, URLs without a usable name get
+/// `image-<n>`, and duplicates are prefixed with their position.
+fn image_file_names(urls: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+
+    urls.iter()
+        .enumerate()
+        .map(|(index, url)| {
+            let name = reqwest::Url::parse(url)
+                .ok()
+                .and_then(|url| url.path_segments()?.next_back().map(str::to_string))
+                .map(|segment| {
+                    segment
+                        .chars()
+                        .map(|c| {
+                            if c.is_ascii_alphanumeric() || "._-".contains(c) {
+                                c
+                            } else {
+                                '_'
+                            }
+                        })
+                        .collect::<String>()
+                })
+                .filter(|name| !name.trim_matches('.').is_empty())
+                .unwrap_or_else(|| format!("image-{}", index + 1));
+
+            if seen.insert(name.clone()) {
+                name
+            } else {
+                let name = format!("{}-{}", index + 1, name);
+                seen.insert(name.clone());
+                name
+            }
+        })
+        .collect()
+}
+
+/// Parse the `Retry-After` header, which is either a number of seconds or an HTTP-date
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Read the `X-RateLimit-*` headers, if the API sent any
+fn rate_limit_info(headers: &reqwest::header::HeaderMap) -> Option<RateLimitInfo> {
+    fn header<T: std::str::FromStr>(headers: &reqwest::header::HeaderMap, name: &str) -> Option<T> {
+        headers.get(name)?.to_str().ok()?.trim().parse().ok()
+    }
+
+    let info = RateLimitInfo {
+        remaining: header(headers, "x-ratelimit-remaining"),
+        reset_at: header(headers, "x-ratelimit-reset"),
+        limit: header(headers, "x-ratelimit-limit"),
+    };
+    (info != RateLimitInfo::default()).then_some(info)
+}
+
+/// Randomize a delay uniformly within ± `fraction` of its length
+fn jittered(delay: Duration, fraction: f64) -> Duration {
+    let factor = 1.0 + fraction * (fastrand::f64() * 2.0 - 1.0);
+    delay.mul_f64(factor)
+}
+
+/// Task ID looked up by [`ValyuClient::verify_api_key`]; no real task has it
+const KEY_CHECK_TASK_ID: &str = "valyu-sdk-key-check";
+
+/// Longest raw query recorded in [`ValyuError::WithContext`], in characters
+const CONTEXT_QUERY_CHARS: usize = 80;
+
+/// Describe a query for inclusion in an error: just its length, or its
+/// quoted start when raw queries are allowed
+fn context_query(query: &str, raw: bool) -> String {
+    if !raw {
+        return format!("<redacted, {} chars>", query.chars().count());
+    }
+
+    match query.char_indices().nth(CONTEXT_QUERY_CHARS) {
+        Some((end, _)) => format!("\"{}...\"", &query[..end]),
+        None => format!("\"{}\"", query),
+    }
+}
+
+/// Emit an event with the cost of a successful call, for aggregating spend
+/// from logs
+#[cfg(feature = "tracing")]
+fn log_cost(endpoint: &str, tx_id: Option<&str>, cost_usd: Option<f64>) {
+    tracing::info!(
+        valyu.endpoint = endpoint,
+        valyu.tx_id = tx_id,
+        valyu.cost_usd = cost_usd,
+        "valyu API call cost"
+    );
+}
 
 /// Client for interacting with the Valyu API
 ///
+/// The `Debug` output masks the API key, showing only its last few
+/// characters, so clients can be logged safely.
+///
 /// # Example
 ///
 /// ```no_run
@@ -23,20 +219,54 @@ const API_BASE_URL: &str = "https://api.valyu.ai/v1";
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let client = ValyuClient::new("your-api-key");
 ///     let response = client.search("quantum computing").await?;
-///     println!("Found {} results", response.results.as_ref().map(|r| r.len()).unwrap_or(0));
+///     println!("Found {} results", response.result_count());
 ///     Ok(())
 /// }
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ValyuClient {
-    api_key: String,
-    client: reqwest::Client,
-    base_url: String,
+    pub(crate) api_key: String,
+    pub(crate) auth_mode: AuthMode,
+    pub(crate) client: reqwest::Client,
+    pub(crate) base_url: String,
+    pub(crate) endpoint_bases: HashMap<Endpoint, String>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) concurrency_limit: Option<Arc<Semaphore>>,
+    pub(crate) default_headers: reqwest::header::HeaderMap,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) last_raw_response: Option<Arc<Mutex<Option<serde_json::Value>>>>,
+    pub(crate) error_context: bool,
+    pub(crate) error_context_raw_query: bool,
+    pub(crate) default_tool_call: Option<bool>,
+}
+
+impl std::fmt::Debug for ValyuClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValyuClient")
+            .field("api_key", &redact_api_key(&self.api_key))
+            .field("auth_mode", &self.auth_mode)
+            .field("base_url", &self.base_url)
+            .field("endpoint_bases", &self.endpoint_bases)
+            .field("timeout", &self.timeout)
+            .field("retry_policy", &self.retry_policy)
+            .field("concurrency_limit", &self.concurrency_limit)
+            .field("default_headers", &self.default_headers)
+            .field("transport", &self.transport)
+            .field("error_context", &self.error_context)
+            .field("error_context_raw_query", &self.error_context_raw_query)
+            .field("default_tool_call", &self.default_tool_call)
+            .finish_non_exhaustive()
+    }
 }
 
 impl ValyuClient {
     /// Create a new Valyu client with an API key
     ///
+    /// Requests time out after 60 seconds, and connection attempts after 10
+    /// seconds, so a stalled connection can't hang forever. Use
+    /// [`builder`](Self::builder) to change either timeout.
+    ///
     /// # Example
     ///
     /// ```
@@ -44,11 +274,20 @@ impl ValyuClient {
     ///
     /// let client = ValyuClient::new("your-api-key");
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be initialized (e.g. the TLS backend
+    /// fails to load), like [`reqwest::Client::new`].
     pub fn new(api_key: impl Into<String>) -> Self {
+        let client = reqwest::Client::builder()
+            .connect_timeout(DEFAULT_CONNECT_TIMEOUT)
+            .build()
+            .expect("failed to initialize the HTTP client");
+
         Self {
-            api_key: api_key.into(),
-            client: reqwest::Client::new(),
-            base_url: API_BASE_URL.to_string(),
+            timeout: Some(DEFAULT_TIMEOUT),
+            ..Self::with_client(api_key, client)
         }
     }
 
@@ -65,15 +304,16 @@ impl ValyuClient {
     /// ```
     pub fn with_base_url(api_key: impl Into<String>, base_url: impl Into<String>) -> Self {
         Self {
-            api_key: api_key.into(),
-            client: reqwest::Client::new(),
             base_url: base_url.into(),
+            ..Self::new(api_key)
         }
     }
 
     /// Create a new Valyu client with a custom reqwest client
     ///
-    /// This allows you to configure custom timeout, proxy settings, etc.
+    /// This allows you to configure custom timeout, proxy settings, etc. The
+    /// SDK's default timeouts are not applied; the client's own settings are
+    /// used as-is.
     ///
     /// # Example
     ///
@@ -91,99 +331,212 @@ impl ValyuClient {
     pub fn with_client(api_key: impl Into<String>, client: reqwest::Client) -> Self {
         Self {
             api_key: api_key.into(),
-            client,
+            auth_mode: AuthMode::default(),
+            client: client.clone(),
             base_url: API_BASE_URL.to_string(),
+            endpoint_bases: HashMap::new(),
+            timeout: None,
+            retry_policy: RetryPolicy::default(),
+            concurrency_limit: None,
+            default_headers: reqwest::header::HeaderMap::new(),
+            transport: Arc::new(ReqwestTransport::new(client)),
+            last_raw_response: None,
+            error_context: false,
+            error_context_raw_query: false,
+            default_tool_call: None,
         }
     }
 
-    /// Perform a deep search query with full control over request parameters
+    /// Create a client that uses a different API key but otherwise shares
+    /// this client's configuration
+    ///
+    /// The HTTP client and its connection pool, base URLs, retry policy,
+    /// default headers, and concurrency limit are shared, so this is cheap
+    /// enough to call per request in a multi-tenant service. The new client
+    /// starts with its own (empty) [`last_raw_response`](Self::last_raw_response),
+    /// so one tenant's responses are never visible through another's client.
     ///
     /// # Example
     ///
-    /// ```no_run
-    /// use valyu::{ValyuClient, DeepSearchRequest};
+    /// ```
+    /// use valyu::ValyuClient;
     ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = ValyuClient::new("your-api-key");
+    /// let shared = ValyuClient::new("default-key");
+    /// let tenant = shared.with_api_key("tenant-key");
+    /// ```
+    pub fn with_api_key(&self, api_key: impl Into<String>) -> ValyuClient {
+        Self {
+            api_key: api_key.into(),
+            last_raw_response: self.last_raw_response.as_ref().map(|_| Default::default()),
+            ..self.clone()
+        }
+    }
+
+    /// Create a new Valyu client from environment variables
     ///
-    ///     let request = DeepSearchRequest::new("artificial intelligence")
-    ///         .with_max_results(10)
-    ///         .with_search_type("web")
-    ///         .with_fast_mode(true);
+    /// Reads the API key from `VALYU_API_KEY` and, if set, a custom base URL
+    /// from `VALYU_BASE_URL`.
     ///
-    ///     let response = client.deep_search(&request).await?;
-    ///     Ok(())
-    /// }
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// let client = ValyuClient::from_env()?;
+    /// # Ok::<(), valyu::ValyuError>(())
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The HTTP request fails
-    /// - The API returns an error response
-    /// - The response cannot be parsed
-    pub async fn deep_search(&self, request: &DeepSearchRequest) -> Result<DeepSearchResponse> {
-        let url = format!("{}/deepsearch", self.base_url);
+    /// Returns [`ValyuError::MissingApiKey`] if `VALYU_API_KEY` is unset or empty.
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Content-Type", "application/json")
-            .header("x-api-key", &self.api_key)
-            .json(request)
-            .send()
-            .await?;
+    /// Build a client from a variable lookup, so `from_env` can be tested without
+    /// touching the process environment
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let mut builder = Self::builder();
 
-        let status = response.status();
+        if let Some(api_key) = lookup(API_KEY_ENV) {
+            builder = builder.api_key(api_key.trim());
+        }
 
-        // Handle specific HTTP status codes
-        match status.as_u16() {
-            200 | 206 => {
-                // Success - continue to parse response
-            }
-            401 | 403 => {
-                return Err(ValyuError::InvalidApiKey);
-            }
-            429 => {
-                return Err(ValyuError::RateLimitExceeded);
-            }
-            503 => {
-                return Err(ValyuError::ServiceUnavailable);
-            }
-            _ => {
-                let error_text = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
-            }
+        if let Some(base_url) = lookup(BASE_URL_ENV).filter(|url| !url.trim().is_empty()) {
+            builder = builder.base_url(base_url.trim());
         }
 
-        let search_response: DeepSearchResponse = response.json().await.map_err(|e| {
-            ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-        })?;
+        builder.build()
+    }
+
+    /// Create a builder for configuring a client
+    ///
+    /// The builder combines everything the other constructors offer, plus
+    /// request timeouts and automatic retries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{RetryPolicy, ValyuClient};
+    /// use std::time::Duration;
+    ///
+    /// let client = ValyuClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .base_url("https://custom.api.url/v1")
+    ///     .timeout(Duration::from_secs(30))
+    ///     .retry_policy(RetryPolicy::new(3))
+    ///     .build()?;
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    pub fn builder() -> ValyuClientBuilder {
+        ValyuClientBuilder::new()
+    }
+
+    /// Base URL for an endpoint, honoring any per-endpoint override
+    fn base_url_for(&self, endpoint: Endpoint) -> &str {
+        self.endpoint_bases
+            .get(&endpoint)
+            .map_or(&self.base_url, String::as_str)
+    }
 
-        // Check if the API returned an error in the response body
-        if !search_response.success {
-            if let Some(error) = &search_response.error {
-                return Err(ValyuError::ApiError(error.clone()));
+    /// Send a request, retrying transient failures according to the retry policy
+    ///
+    /// `timeout` overrides the client-wide timeout for this request only.
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        timeout: Option<Duration>,
+    ) -> Result<reqwest::Response> {
+        let request = request.headers(self.default_headers.clone());
+        let request = self.auth_mode.apply(request, &self.api_key);
+        let request = match timeout.or(self.timeout) {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
+        let request = request.build()?;
+
+        let mut attempt = 0;
+        loop {
+            // Only streaming bodies can't be cloned; we never send those, but
+            // fall back to a single attempt rather than panicking
+            let Some(current) = request.try_clone() else {
+                return self.execute(request).await;
+            };
+
+            let result = self.execute(current).await;
+
+            let delay = match &result {
+                Ok(response) if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                    Some(
+                        retry_after(response.headers())
+                            .unwrap_or_else(|| self.retry_policy.backoff(attempt)),
+                    )
+                }
+                Ok(response) if response.status() == reqwest::StatusCode::SERVICE_UNAVAILABLE => {
+                    Some(self.retry_policy.backoff(attempt))
+                }
+                Err(e) if e.is_retryable() => Some(self.retry_policy.backoff(attempt)),
+                _ => None,
+            };
+
+            match delay {
+                Some(delay) if attempt < self.retry_policy.max_retries => {
+                    // The response holds the concurrency slot; free it for
+                    // other calls while backing off
+                    drop(result);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                _ => {
+                    // Recorded on the calling method's span
+                    #[cfg(feature = "tracing")]
+                    if let Ok(response) = &result {
+                        tracing::Span::current().record("status", response.status().as_u16());
+                    }
+                    return result;
+                }
             }
-            return Err(ValyuError::ApiError(
-                "API request was not successful".to_string(),
-            ));
+        }
+    }
+
+    /// Send a single request through the transport, waiting for a free slot
+    /// first if the client limits concurrency
+    ///
+    /// The slot is stored in the response's extensions, so it stays taken
+    /// until the response, and with it the body, has been read or dropped.
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        // The semaphore is never closed, so acquiring only fails if it were
+        let permit = match &self.concurrency_limit {
+            Some(limit) => limit.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+
+        let mut response = self.transport.execute(request).await?;
+        if let Some(permit) = permit {
+            response.extensions_mut().insert(Arc::new(permit));
+        }
+        Ok(response)
+    }
+
+    /// Read a JSON response body, recording it if raw capture is enabled
+    async fn read_json(&self, response: reqwest::Response) -> Result<serde_json::Value> {
+        let body = response.bytes().await?;
+        let value: serde_json::Value = serde_json::from_slice(&body)
+            .map_err(|e| ValyuError::ParseError(format!("Failed to parse API response: {}", e)))?;
+
+        if let Some(last_raw) = &self.last_raw_response {
+            *last_raw.lock().unwrap_or_else(|e| e.into_inner()) = Some(value.clone());
         }
 
-        Ok(search_response)
+        Ok(value)
     }
 
-    /// Convenience method to perform a simple query with default settings
+    /// The raw JSON body of the most recent API response
     ///
-    /// This is equivalent to creating a `DeepSearchRequest` with just a query
-    /// and calling `deep_search`.
+    /// Only available when the client was built with
+    /// [`capture_raw(true)`](crate::ValyuClientBuilder::capture_raw); returns
+    /// `None` otherwise or before the first response. Clones of the client
+    /// share the captured body.
     ///
     /// # Example
     ///
@@ -192,78 +545,213 @@ impl ValyuClient {
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = ValyuClient::new("your-api-key");
-    ///     let response = client.search("quantum computing").await?;
+    ///     let client = ValyuClient::builder()
+    ///         .api_key("your-api-key")
+    ///         .capture_raw(true)
+    ///         .build()?;
     ///
-    ///     if let Some(results) = &response.results {
-    ///         for result in results {
-    ///             println!("{}", result.title.as_deref().unwrap_or("Untitled"));
-    ///         }
+    ///     client.search("quantum computing").await?;
+    ///     if let Some(raw) = client.last_raw_response() {
+    ///         println!("{}", serde_json::to_string_pretty(&raw)?);
     ///     }
     ///     Ok(())
     /// }
     /// ```
+    pub fn last_raw_response(&self) -> Option<serde_json::Value> {
+        let last_raw = self.last_raw_response.as_ref()?;
+        last_raw.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Run an API call, wrapping its error in [`ValyuError::WithContext`] when
+    /// error context is enabled
+    async fn with_error_context<T>(
+        &self,
+        endpoint: &str,
+        query: Option<&str>,
+        call: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let result = call.await;
+        if !self.error_context {
+            return result;
+        }
+
+        result.map_err(|error| ValyuError::WithContext {
+            source: Box::new(error),
+            endpoint: endpoint.to_string(),
+            query: query.map(|query| context_query(query, self.error_context_raw_query)),
+        })
+    }
+
+    /// Check that the API key is accepted, without spending credits
+    ///
+    /// Sends `GET /deepresearch/tasks/valyu-sdk-key-check/status`, a status
+    /// lookup for a task that doesn't exist, so no search or research is run.
+    ///
+    /// With a valid key the API answers `404 Not Found`, since the task
+    /// doesn't exist; that and any 2xx status count as success. A rejected key
+    /// gets 401 or 403. Any other status, including server errors, means the
+    /// check couldn't confirm the key and the service, so it is an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::from_env()?;
+    ///     client.verify_api_key().await?;
+    ///     // ... start the batch job
+    ///     Ok(())
+    /// }
+    /// ```
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The HTTP request fails
-    /// - The API returns an error response
-    /// - The response cannot be parsed
-    pub async fn search(&self, query: impl Into<String>) -> Result<DeepSearchResponse> {
-        let request = DeepSearchRequest::new(query);
-        self.deep_search(&request).await
+    /// Returns [`ValyuError::InvalidApiKey`] if the key is rejected (HTTP 401
+    /// or 403), [`ValyuError::ServiceUnavailable`] on HTTP 503,
+    /// [`ValyuError::RateLimitExceeded`] on HTTP 429,
+    /// [`ValyuError::HttpStatus`] for any other unexpected status, or a
+    /// transport error if the API can't be reached.
+    pub async fn verify_api_key(&self) -> Result<()> {
+        let path = format!("deepresearch/tasks/{}/status", KEY_CHECK_TASK_ID);
+
+        self.with_error_context(&path, None, async {
+            let url = format!("{}/{}", self.base_url_for(Endpoint::DeepResearch), path);
+            let response = self.send(self.client.get(&url), None).await?;
+
+            let status = response.status();
+            match status.as_u16() {
+                200..=299 | 404 => Ok(()),
+                401 | 403 => Err(ValyuError::InvalidApiKey),
+                429 => Err(ValyuError::RateLimitExceeded {
+                    retry_after: retry_after(response.headers()),
+                }),
+                503 => Err(ValyuError::ServiceUnavailable),
+                _ => {
+                    let body = response
+                        .text()
+                        .await
+                        .unwrap_or_else(|_| "Unknown error".to_string());
+                    Err(ValyuError::HttpStatus {
+                        status: status.as_u16(),
+                        body,
+                    })
+                }
+            }
+        })
+        .await
     }
 
-    /// Extract and process content from URLs
+    /// Perform a deep search query with full control over request parameters
     ///
-    /// Process up to 10 URLs and extract their content in a clean, structured format.
+    /// Accepts a request by value or by reference, or a plain query string
+    /// for a request with default settings.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use valyu::{ValyuClient, ContentsRequest};
+    /// use valyu::{ValyuClient, DeepSearchRequest};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
     ///
-    ///     let request = ContentsRequest::new(vec![
-    ///         "https://example.com/article1".to_string(),
-    ///         "https://example.com/article2".to_string(),
-    ///     ])
-    ///     .with_response_length("medium")
-    ///     .with_extract_effort("high");
-    ///
-    ///     let response = client.contents(&request).await?;
+    ///     let request = DeepSearchRequest::new("artificial intelligence")
+    ///         .with_max_results(10)
+    ///         .with_search_type("web")
+    ///         .with_fast_mode(true);
     ///
-    ///     if let Some(results) = &response.results {
-    ///         for result in results {
-    ///             println!("Title: {}", result.title.as_deref().unwrap_or("Untitled"));
-    ///         }
-    ///     }
+    ///     let response = client.deep_search(&request).await?;
     ///     Ok(())
     /// }
     /// ```
     ///
+    /// A successful search always has `results` set: when nothing matched it
+    /// is `Some` of an empty list, even if the API omitted the field.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The request fails validation (see [`DeepSearchRequest::validate`])
     /// - The HTTP request fails
     /// - The API returns an error response
     /// - The response cannot be parsed
-    /// - More than 10 URLs are provided
-    pub async fn contents(&self, request: &ContentsRequest) -> Result<ContentsResponse> {
-        let url = format!("{}/contents", self.base_url);
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.deep_search",
+            skip_all,
+            fields(
+                endpoint = "deepsearch",
+                query_len = tracing::field::Empty,
+                status = tracing::field::Empty,
+                tx_id = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn deep_search(
+        &self,
+        request: impl Into<DeepSearchRequest>,
+    ) -> Result<DeepSearchResponse> {
+        let request = request.into();
+
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("query_len", request.query.len());
+
+        self.with_error_context("deepsearch", Some(&request.query), async {
+            let response = self.deep_search_response(&request).await?;
+            let status = response.status();
+            let rate_limit = rate_limit_info(response.headers());
+
+            let value = self.read_json(response).await?;
+            // Fails with the API's error message and code if `success` is false
+            let mut search_response: DeepSearchResponse = parse_api_value(value)?;
+            search_response.http_status = Some(status.as_u16());
+            search_response.rate_limit = rate_limit;
+            // A successful search with no matches may omit `results`
+            if search_response.success && search_response.results.is_none() {
+                search_response.results = Some(Vec::new());
+            }
+
+            #[cfg(feature = "tracing")]
+            {
+                tracing::Span::current().record("tx_id", search_response.tx_id.as_deref());
+                log_cost(
+                    "deepsearch",
+                    search_response.tx_id.as_deref(),
+                    search_response.cost_dollars(),
+                );
+            }
+
+            Ok(search_response)
+        })
+        .await
+    }
 
-        let response = self
+    /// Send a deep search request and map error statuses, leaving the body unread
+    async fn deep_search_response(&self, request: &DeepSearchRequest) -> Result<reqwest::Response> {
+        request.validate()?;
+
+        // Apply the client-wide default without overriding the request
+        let defaulted;
+        let request = match self.default_tool_call {
+            Some(is_tool_call) if request.is_tool_call.is_none() => {
+                defaulted = request.clone().with_is_tool_call(is_tool_call);
+                &defaulted
+            }
+            _ => request,
+        };
+
+        let url = format!("{}/deepsearch", self.base_url_for(Endpoint::DeepSearch));
+
+        let builder = self
             .client
             .post(&url)
             .header("Content-Type", "application/json")
-            .header("x-api-key", &self.api_key)
-            .json(request)
-            .send()
-            .await?;
+            .json(request);
+
+        let response = self.send(builder, request.timeout).await?;
 
         let status = response.status();
 
@@ -283,73 +771,82 @@ impl ValyuClient {
                 return Err(ValyuError::InvalidApiKey);
             }
             402 => {
-                return Err(ValyuError::ApiError("Insufficient credits".to_string()));
-            }
-            422 => {
-                return Err(ValyuError::ApiError("All URLs failed processing".to_string()));
+                return Err(ValyuError::InsufficientCredits);
             }
             429 => {
-                return Err(ValyuError::RateLimitExceeded);
+                return Err(ValyuError::RateLimitExceeded {
+                    retry_after: retry_after(response.headers()),
+                });
             }
             503 => {
                 return Err(ValyuError::ServiceUnavailable);
             }
             _ => {
-                let error_text = response
+                let body = response
                     .text()
                     .await
                     .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
+                return Err(ValyuError::HttpStatus {
+                    status: status.as_u16(),
+                    body,
+                });
             }
         }
 
-        let contents_response: ContentsResponse = response.json().await.map_err(|e| {
-            ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-        })?;
-
-        // Check if the API returned an error in the response body
-        if !contents_response.success {
-            if let Some(error) = &contents_response.error {
-                return Err(ValyuError::ApiError(error.clone()));
-            }
-            return Err(ValyuError::ApiError(
-                "API request was not successful".to_string(),
-            ));
-        }
-
-        Ok(contents_response)
+        Ok(response)
     }
 
-    /// Get AI-powered answers with automatic source retrieval
+    /// Like [`deep_search`](Self::deep_search), but return the untyped JSON body
     ///
-    /// Ask questions and get comprehensive answers backed by relevant sources.
+    /// Useful for inspecting fields the typed response doesn't model yet,
+    /// while keeping the client's authentication and retry handling.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use valyu::{ValyuClient, AnswerRequest};
+    /// use valyu::{DeepSearchRequest, ValyuClient};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
+    ///     let request = DeepSearchRequest::new("quantum computing");
+    ///     let raw = client.deep_search_raw(&request).await?;
+    ///     println!("{}", serde_json::to_string_pretty(&raw)?);
+    ///     Ok(())
+    /// }
+    /// ```
     ///
-    ///     let request = AnswerRequest::new("What are the latest developments in quantum computing?")
-    ///         .with_search_type("web")
-    ///         .with_system_instructions("Focus on breakthroughs from 2024");
-    ///
-    ///     let response = client.answer(&request).await?;
+    /// # Errors
     ///
-    ///     if let Some(contents) = &response.contents {
-    ///         println!("Answer: {}", contents);
-    ///     }
+    /// Returns the same errors as [`deep_search`](Self::deep_search), except that a
+    /// `success: false` body is returned as-is instead of as
+    /// [`ValyuError::ApiError`].
+    pub async fn deep_search_raw(&self, request: &DeepSearchRequest) -> Result<serde_json::Value> {
+        self.with_error_context("deepsearch", Some(&request.query), async {
+            let response = self.deep_search_response(request).await?;
+            self.read_json(response).await
+        })
+        .await
+    }
+
+    /// Convenience method to perform a simple query with default settings
     ///
-    ///     if let Some(sources) = &response.search_results {
-    ///         println!("\nSources ({}):", sources.len());
-    ///         for source in sources {
-    ///             println!("  - {}", source.title.as_deref().unwrap_or("Untitled"));
+    /// This is equivalent to creating a `DeepSearchRequest` with just a query
+    /// and calling `deep_search`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let response = client.search("quantum computing").await?;
+    ///
+    ///     if let Some(results) = &response.results {
+    ///         for result in results {
+    ///             println!("{}", result.title.as_deref().unwrap_or("Untitled"));
     ///         }
     ///     }
     ///     Ok(())
@@ -362,90 +859,140 @@ impl ValyuClient {
     /// - The HTTP request fails
     /// - The API returns an error response
     /// - The response cannot be parsed
-    pub async fn answer(&self, request: &AnswerRequest) -> Result<AnswerResponse> {
-        let url = format!("{}/answer", self.base_url);
+    pub async fn search(&self, query: impl Into<String>) -> Result<DeepSearchResponse> {
+        self.deep_search(DeepSearchRequest::new(query)).await
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Content-Type", "application/json")
-            .header("x-api-key", &self.api_key)
-            .json(request)
-            .send()
-            .await?;
+    /// Run many DeepSearch requests concurrently
+    ///
+    /// At most `concurrency` requests are in flight at once (a value of 0 is
+    /// treated as 1). Results are returned in the same order as `requests`, and
+    /// a failed request does not stop the others.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{ValyuClient, DeepSearchRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///
+    ///     let requests: Vec<_> = ["quantum computing", "fusion energy", "CRISPR"]
+    ///         .into_iter()
+    ///         .map(DeepSearchRequest::new)
+    ///         .collect();
+    ///
+    ///     for (request, result) in requests.iter().zip(client.search_batch(&requests, 4).await) {
+    ///         match result {
+    ///             Ok(response) => println!("{}: {:?}", request.query, response.tx_id),
+    ///             Err(e) => eprintln!("{}: {}", request.query, e),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn search_batch(
+        &self,
+        requests: &[DeepSearchRequest],
+        concurrency: usize,
+    ) -> Vec<Result<DeepSearchResponse>> {
+        stream::iter(requests)
+            .map(|request| self.deep_search(request))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
 
-        let status = response.status();
+    /// Run many DeepSearch requests concurrently, stopping at the first fatal
+    /// error
+    ///
+    /// Like [`search_batch`](Self::search_batch), but as soon as a request
+    /// fails with an error that isn't [retryable](ValyuError::is_retryable)
+    /// (e.g. an invalid API key), the requests still in flight are dropped,
+    /// the rest are never sent, and that error is returned. Retryable errors
+    /// are retried according to the client's [`RetryPolicy`](crate::RetryPolicy)
+    /// and don't stop the batch; if one still fails after its retries, the
+    /// batch finishes and returns the first such error.
+    ///
+    /// On success the responses are in the same order as `requests`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{ValyuClient, DeepSearchRequest};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///
+    ///     let requests: Vec<_> = ["quantum computing", "fusion energy", "CRISPR"]
+    ///         .into_iter()
+    ///         .map(DeepSearchRequest::new)
+    ///         .collect();
+    ///
+    ///     let responses = client.search_batch_try(&requests, 4).await?;
+    ///     println!("{} searches", responses.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn search_batch_try(
+        &self,
+        requests: &[DeepSearchRequest],
+        concurrency: usize,
+    ) -> Result<Vec<DeepSearchResponse>> {
+        let mut results = stream::iter(requests.iter().enumerate())
+            .map(|(index, request)| async move { (index, self.deep_search(request).await) })
+            .buffer_unordered(concurrency.max(1));
 
-        // Handle specific HTTP status codes
-        match status.as_u16() {
-            200 => {
-                // Success - continue to parse response
-            }
-            400 => {
-                let error_text = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Invalid request parameters".to_string());
-                return Err(ValyuError::InvalidRequest(error_text));
-            }
-            401 | 403 => {
-                return Err(ValyuError::InvalidApiKey);
-            }
-            402 => {
-                return Err(ValyuError::ApiError("Insufficient credits".to_string()));
-            }
-            429 => {
-                return Err(ValyuError::RateLimitExceeded);
-            }
-            503 => {
-                return Err(ValyuError::ServiceUnavailable);
-            }
-            _ => {
-                let error_text = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
+        let mut responses: Vec<Option<DeepSearchResponse>> = vec![None; requests.len()];
+        let mut retryable_error = None;
+        while let Some((index, result)) = results.next().await {
+            match result {
+                Ok(response) => responses[index] = Some(response),
+                Err(e) if e.is_retryable() => {
+                    retryable_error.get_or_insert(e);
+                }
+                Err(e) => return Err(e),
             }
         }
 
-        let answer_response: AnswerResponse = response.json().await.map_err(|e| {
-            ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-        })?;
-
-        // Check if the API returned an error in the response body
-        if !answer_response.success {
-            if let Some(error) = &answer_response.error {
-                return Err(ValyuError::ApiError(error.clone()));
-            }
-            return Err(ValyuError::ApiError(
-                "API request was not successful".to_string(),
-            ));
+        match retryable_error {
+            Some(e) => Err(e),
+            None => Ok(responses.into_iter().flatten().collect()),
         }
-
-        Ok(answer_response)
     }
 
-    /// Convenience method to get an answer with default settings
+    /// Run DeepSearch requests one at a time until a dollar budget is spent
     ///
-    /// This is equivalent to creating an `AnswerRequest` with just a query
-    /// and calling `answer`.
+    /// Before each request, the most expensive search so far is taken as the
+    /// estimate for the next one, and the run stops if that estimate would
+    /// take the total over `budget_usd`. Costs come from
+    /// `total_deduction_dollars`; since the API only reports them after the
+    /// fact, an unusually expensive search can still overshoot the budget.
+    /// Failed attempts are retried according to the client's
+    /// [`RetryPolicy`](crate::RetryPolicy).
+    ///
+    /// Returns the responses received and the total amount spent. If a search
+    /// fails, the run stops and the error is returned in
+    /// [`BudgetedSearchResult::error`] alongside the responses already paid for.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use valyu::ValyuClient;
+    /// use valyu::{ValyuClient, DeepSearchRequest};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
-    ///     let response = client.ask("What is quantum computing?").await?;
     ///
-    ///     if let Some(contents) = &response.contents {
-    ///         println!("Answer: {}", contents);
+    ///     let requests = ["quantum computing", "fusion energy", "CRISPR"]
+    ///         .into_iter()
+    ///         .map(DeepSearchRequest::new);
+    ///
+    ///     let run = client.search_until_budget(requests, 0.50).await?;
+    ///     println!("{} searches for ${:.4}", run.responses.len(), run.spent_usd);
+    ///     if let Some(e) = run.error {
+    ///         eprintln!("Stopped early: {}", e);
     ///     }
     ///     Ok(())
     /// }
@@ -453,59 +1000,142 @@ impl ValyuClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The HTTP request fails
-    /// - The API returns an error response
-    /// - The response cannot be parsed
-    pub async fn ask(&self, query: impl Into<String>) -> Result<AnswerResponse> {
-        let request = AnswerRequest::new(query);
-        self.answer(&request).await
-    }
+    /// Returns [`ValyuError::InvalidRequest`] if `budget_usd` is negative or
+    /// not a finite number.
+    pub async fn search_until_budget(
+        &self,
+        requests: impl Iterator<Item = DeepSearchRequest>,
+        budget_usd: f64,
+    ) -> Result<BudgetedSearchResult> {
+        if !budget_usd.is_finite() || budget_usd < 0.0 {
+            return Err(ValyuError::InvalidRequest(format!(
+                "budget_usd must be a non-negative amount, got {}",
+                budget_usd
+            )));
+        }
 
-    // ========== DeepResearch API Methods ==========
+        let mut run = BudgetedSearchResult::default();
+        let mut max_cost: f64 = 0.0;
 
-    /// Create a new DeepResearch task
+        for request in requests {
+            if run.spent_usd >= budget_usd || run.spent_usd + max_cost > budget_usd {
+                break;
+            }
+
+            let response = match self.deep_search(request).await {
+                Ok(response) => response,
+                Err(e) => {
+                    run.error = Some(e);
+                    break;
+                }
+            };
+            let cost = response.total_deduction_dollars.unwrap_or(0.0);
+            run.spent_usd += cost;
+            max_cost = max_cost.max(cost);
+            run.responses.push(response);
+        }
+
+        Ok(run)
+    }
+
+    /// Extract and process content from URLs
     ///
-    /// Starts an asynchronous research task that performs comprehensive research
-    /// across multiple sources and generates detailed reports.
+    /// Process up to 10 URLs and extract their content in a clean, structured format.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use valyu::{ValyuClient, DeepResearchCreateRequest, DeepResearchMode};
+    /// use valyu::{ValyuClient, ContentsRequest};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
     ///
-    ///     let request = DeepResearchCreateRequest::new("What are the key differences between RAG and fine-tuning?")
-    ///         .with_mode(DeepResearchMode::Lite)
-    ///         .with_output_formats(vec!["markdown".to_string()]);
+    ///     let request = ContentsRequest::new(vec![
+    ///         "https://example.com/article1".to_string(),
+    ///         "https://example.com/article2".to_string(),
+    ///     ])
+    ///     .with_response_length("medium")
+    ///     .with_extract_effort("high");
     ///
-    ///     let response = client.deepresearch_create(&request).await?;
-    ///     println!("Task created: {:?}", response.deepresearch_id);
+    ///     let response = client.contents(&request).await?;
+    ///
+    ///     if let Some(results) = &response.results {
+    ///         for result in results {
+    ///             println!("Title: {}", result.title.as_deref().unwrap_or("Untitled"));
+    ///         }
+    ///     }
     ///     Ok(())
     /// }
     /// ```
-    pub async fn deepresearch_create(
-        &self,
-        request: &DeepResearchCreateRequest,
-    ) -> Result<DeepResearchCreateResponse> {
-        let url = format!("{}/deepresearch/tasks", self.base_url);
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The request fails validation (see [`ContentsRequest::validate`]), e.g.
+    ///   no URLs or more than 10 URLs are provided
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.contents",
+            skip_all,
+            fields(
+                endpoint = "contents",
+                url_count = request.urls.len(),
+                status = tracing::field::Empty,
+                tx_id = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn contents(&self, request: &ContentsRequest) -> Result<ContentsResponse> {
+        self.with_error_context("contents", Some(&request.urls.join(" ")), async {
+            let response = self.contents_response(request).await?;
+            let status = response.status();
+            let rate_limit = rate_limit_info(response.headers());
+
+            let value = self.read_json(response).await?;
+            // Fails with the API's error message and code if `success` is false
+            let mut contents_response: ContentsResponse = parse_api_value(value)?;
+            contents_response.http_status = Some(status.as_u16());
+            contents_response.rate_limit = rate_limit;
+
+            #[cfg(feature = "tracing")]
+            {
+                tracing::Span::current().record("tx_id", contents_response.tx_id.as_deref());
+                log_cost(
+                    "contents",
+                    contents_response.tx_id.as_deref(),
+                    contents_response.cost_dollars(),
+                );
+            }
+
+            Ok(contents_response)
+        })
+        .await
+    }
+
+    /// Send a contents request and map error statuses, leaving the body unread
+    async fn contents_response(&self, request: &ContentsRequest) -> Result<reqwest::Response> {
+        request.validate()?;
 
-        let response = self
+        let url = format!("{}/contents", self.base_url_for(Endpoint::Contents));
+
+        let builder = self
             .client
             .post(&url)
             .header("Content-Type", "application/json")
-            .header("x-api-key", &self.api_key)
-            .json(request)
-            .send()
-            .await?;
+            .json(request);
+
+        let response = self.send(builder, request.timeout).await?;
 
         let status = response.status();
 
+        // Handle specific HTTP status codes
         match status.as_u16() {
-            200 | 201 | 202 => {
+            200 | 206 => {
                 // Success - continue to parse response
             }
             400 => {
@@ -519,43 +1149,77 @@ impl ValyuClient {
                 return Err(ValyuError::InvalidApiKey);
             }
             402 => {
-                return Err(ValyuError::ApiError("Insufficient credits".to_string()));
+                return Err(ValyuError::InsufficientCredits);
+            }
+            413 => {
+                return Err(ValyuError::PayloadTooLarge);
+            }
+            422 => {
+                return Err(ValyuError::ApiError {
+                    message: "All URLs failed processing".to_string(),
+                    code: None,
+                });
             }
             429 => {
-                return Err(ValyuError::RateLimitExceeded);
+                return Err(ValyuError::RateLimitExceeded {
+                    retry_after: retry_after(response.headers()),
+                });
             }
             503 => {
                 return Err(ValyuError::ServiceUnavailable);
             }
             _ => {
-                let error_text = response
+                let body = response
                     .text()
                     .await
                     .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
+                return Err(ValyuError::HttpStatus {
+                    status: status.as_u16(),
+                    body,
+                });
             }
         }
 
-        let create_response: DeepResearchCreateResponse = response.json().await.map_err(|e| {
-            ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-        })?;
-
-        if !create_response.success {
-            if let Some(error) = &create_response.error {
-                return Err(ValyuError::ApiError(error.clone()));
-            }
-            return Err(ValyuError::ApiError(
-                "API request was not successful".to_string(),
-            ));
-        }
+        Ok(response)
+    }
 
-        Ok(create_response)
+    /// Like [`contents`](Self::contents), but return the untyped JSON body
+    ///
+    /// Useful for inspecting fields the typed response doesn't model yet,
+    /// while keeping the client's authentication and retry handling.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{ContentsRequest, ValyuClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let request = ContentsRequest::new(vec!["https://example.com".to_string()]);
+    ///     let raw = client.contents_raw(&request).await?;
+    ///     println!("{}", serde_json::to_string_pretty(&raw)?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`contents`](Self::contents), except that a
+    /// `success: false` body is returned as-is instead of as
+    /// [`ValyuError::ApiError`].
+    pub async fn contents_raw(&self, request: &ContentsRequest) -> Result<serde_json::Value> {
+        self.with_error_context("contents", Some(&request.urls.join(" ")), async {
+            let response = self.contents_response(request).await?;
+            self.read_json(response).await
+        })
+        .await
     }
 
-    /// Get the status of a DeepResearch task
+    /// Extract the content of a single URL, using default settings
+    ///
+    /// A shortcut for [`contents`](Self::contents) when only one page is
+    /// needed.
     ///
     /// # Example
     ///
@@ -565,74 +1229,31 @@ impl ValyuClient {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
-    ///     let status = client.deepresearch_status("task-id-here").await?;
-    ///     println!("Status: {:?}", status.status);
+    ///     let content = client.content("https://en.wikipedia.org/wiki/Quantum_computing").await?;
+    ///     println!("{}", content.content_as_string().unwrap_or_default());
     ///     Ok(())
     /// }
     /// ```
-    pub async fn deepresearch_status(
-        &self,
-        task_id: impl AsRef<str>,
-    ) -> Result<DeepResearchStatusResponse> {
-        let url = format!(
-            "{}/deepresearch/tasks/{}/status",
-            self.base_url,
-            task_id.as_ref()
-        );
-
-        let response = self
-            .client
-            .get(&url)
-            .header("x-api-key", &self.api_key)
-            .send()
-            .await?;
-
-        let status = response.status();
-
-        match status.as_u16() {
-            200 => {
-                // Success
-            }
-            401 | 403 => {
-                return Err(ValyuError::InvalidApiKey);
-            }
-            404 => {
-                return Err(ValyuError::ApiError("Task not found".to_string()));
-            }
-            429 => {
-                return Err(ValyuError::RateLimitExceeded);
-            }
-            503 => {
-                return Err(ValyuError::ServiceUnavailable);
-            }
-            _ => {
-                let error_text = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
-            }
-        }
-
-        let status_response: DeepResearchStatusResponse = response.json().await.map_err(|e| {
-            ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-        })?;
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::NotFound`] if no content could be extracted from
+    /// the URL, or any error returned by [`contents`](Self::contents).
+    pub async fn content(&self, url: impl Into<String>) -> Result<ContentResult> {
+        let url = url.into();
+        let request = ContentsRequest::new(vec![url.clone()]);
+        let response = self.contents(&request).await?;
 
-        Ok(status_response)
+        response
+            .results
+            .and_then(|results| results.into_iter().next())
+            .ok_or(ValyuError::NotFound(url))
     }
 
-    /// Wait for a DeepResearch task to complete
-    ///
-    /// Polls the task status until it completes, fails, or is cancelled.
-    ///
-    /// # Arguments
+    /// Fetch the full content of a search result's URL
     ///
-    /// * `task_id` - The task identifier
-    /// * `poll_interval_secs` - Seconds between status checks (default: 5)
-    /// * `max_wait_secs` - Maximum seconds to wait (default: 900 for lite, 5400 for heavy)
+    /// Calls the Contents API for the result's URL with default settings and
+    /// returns the extracted content.
     ///
     /// # Example
     ///
@@ -642,128 +1263,104 @@ impl ValyuClient {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
-    ///     let result = client.deepresearch_wait("task-id", 5, 900).await?;
+    ///     let response = client.search("quantum computing").await?;
     ///
-    ///     if let Some(output) = &result.output {
-    ///         println!("Research output: {}", output);
+    ///     if let Some(result) = response.results_iter().next() {
+    ///         let content = client.fetch_content(result).await?;
+    ///         println!("{}", content.content_as_string().unwrap_or_default());
     ///     }
     ///     Ok(())
     /// }
     /// ```
-    pub async fn deepresearch_wait(
-        &self,
-        task_id: impl AsRef<str>,
-        poll_interval_secs: u64,
-        max_wait_secs: u64,
-    ) -> Result<DeepResearchStatusResponse> {
-        let task_id = task_id.as_ref();
-        let start = std::time::Instant::now();
-        let max_duration = std::time::Duration::from_secs(max_wait_secs);
-        let poll_duration = std::time::Duration::from_secs(poll_interval_secs);
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if the result has no URL,
+    /// [`ValyuError::NotFound`] if no content could be extracted from it, or
+    /// any error returned by [`contents`](Self::contents).
+    pub async fn fetch_content(&self, result: &SearchResult) -> Result<ContentResult> {
+        let url = result
+            .url
+            .as_deref()
+            .ok_or_else(|| ValyuError::InvalidRequest("search result has no URL".to_string()))?;
 
-        loop {
-            let status = self.deepresearch_status(task_id).await?;
-
-            match &status.status {
-                Some(DeepResearchStatus::Completed) => return Ok(status),
-                Some(DeepResearchStatus::Failed) => {
-                    let error_msg = status
-                        .error
-                        .clone()
-                        .unwrap_or_else(|| "Task failed".to_string());
-                    return Err(ValyuError::ApiError(error_msg));
-                }
-                Some(DeepResearchStatus::Cancelled) => {
-                    return Err(ValyuError::ApiError("Task was cancelled".to_string()));
-                }
-                _ => {
-                    // Still queued or running
-                    if start.elapsed() > max_duration {
-                        return Err(ValyuError::ApiError(format!(
-                            "Maximum wait time of {} seconds exceeded",
-                            max_wait_secs
-                        )));
-                    }
-                    tokio::time::sleep(poll_duration).await;
-                }
-            }
-        }
+        self.content(url).await
     }
 
-    /// List DeepResearch tasks
+    /// Download the images of a content result into a directory
+    ///
+    /// Images are downloaded concurrently, named after the last segment of
+    /// their URL path, and the outcome for each image URL is returned in the
+    /// order of [`ContentResult::images`]. The directory is created if needed.
+    ///
+    /// A failed download doesn't abort the batch; its error is collected
+    /// alongside the URL so callers can see which images are missing.
+    ///
+    /// The client's timeout applies to the wait for each response and to
+    /// each stall while reading it, not to the whole download, so large
+    /// images aren't cut off just for taking a while.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use valyu::ValyuClient;
+    /// use valyu::{ContentsRequest, ValyuClient};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
-    ///     let tasks = client.deepresearch_list("api-key-id", Some(50)).await?;
+    ///     let request = ContentsRequest::new(vec!["https://example.com/article".to_string()]);
+    ///     let response = client.contents(&request).await?;
     ///
-    ///     if let Some(data) = &tasks.data {
-    ///         for task in data {
-    ///             println!("{}: {:?}", task.query, task.status);
+    ///     for result in response.results.iter().flatten() {
+    ///         for (url, outcome) in client.download_images(result, "media").await? {
+    ///             match outcome {
+    ///                 Ok(path) => println!("Saved {}", path.display()),
+    ///                 Err(e) => eprintln!("Failed to download {}: {}", url, e),
+    ///             }
     ///         }
     ///     }
     ///     Ok(())
     /// }
     /// ```
-    pub async fn deepresearch_list(
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::Io`] if the directory cannot be created. Failures
+    /// of individual images are reported per URL instead.
+    pub async fn download_images(
         &self,
-        api_key_id: impl AsRef<str>,
-        limit: Option<u32>,
-    ) -> Result<DeepResearchListResponse> {
-        let mut url = format!(
-            "{}/deepresearch/list?api_key_id={}",
-            self.base_url,
-            api_key_id.as_ref()
-        );
-
-        if let Some(l) = limit {
-            url.push_str(&format!("&limit={}", l));
+        result: &ContentResult,
+        dir: impl AsRef<Path>,
+    ) -> Result<Vec<(String, Result<PathBuf>)>> {
+        let dir = dir.as_ref();
+        let urls = result.images.as_deref().unwrap_or_default();
+        if urls.is_empty() {
+            return Ok(Vec::new());
         }
 
-        let response = self
-            .client
-            .get(&url)
-            .header("x-api-key", &self.api_key)
-            .send()
-            .await?;
-
-        let status = response.status();
-
-        match status.as_u16() {
-            200 => {
-                // Success
-            }
-            401 | 403 => {
-                return Err(ValyuError::InvalidApiKey);
-            }
-            429 => {
-                return Err(ValyuError::RateLimitExceeded);
-            }
-            _ => {
-                let error_text = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
-            }
-        }
+        tokio::fs::create_dir_all(dir).await?;
 
-        let list_response: DeepResearchListResponse = response.json().await.map_err(|e| {
-            ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-        })?;
+        let downloads = stream::iter(urls.iter().zip(image_file_names(urls)))
+            .map(|(url, file_name)| async move {
+                let outcome = self.download_file(url, dir.join(file_name)).await;
+                (url.clone(), outcome)
+            })
+            .buffered(IMAGE_DOWNLOAD_CONCURRENCY)
+            .collect()
+            .await;
 
-        Ok(list_response)
+        Ok(downloads)
     }
 
-    /// Add follow-up instructions to a running task
+    /// Download the PDF report of a DeepResearch task to `path`
+    ///
+    /// The PDF is written to disk as it downloads rather than buffered in
+    /// memory. Only tasks created with [`OutputFormat::Pdf`](crate::OutputFormat::Pdf)
+    /// among their output formats have a PDF.
+    ///
+    /// The client's timeout applies to the wait for the response and to each
+    /// stall while reading it, not to the whole download, so a large report
+    /// on a slow connection isn't cut off partway through.
     ///
     /// # Example
     ///
@@ -773,136 +1370,264 @@ impl ValyuClient {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
-    ///     client.deepresearch_update("task-id", "Focus more on peer-reviewed sources").await?;
+    ///     let status = client.deepresearch_wait("task-id", 5, 900).await?;
+    ///
+    ///     let path = client.download_pdf(&status, "report.pdf").await?;
+    ///     println!("Saved {}", path.display());
     ///     Ok(())
     /// }
     /// ```
-    pub async fn deepresearch_update(
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if the task has no `pdf_url`,
+    /// [`ValyuError::HttpStatus`] if the download is refused, and
+    /// [`ValyuError::Io`] if the file cannot be written.
+    pub async fn download_pdf(
         &self,
-        task_id: impl AsRef<str>,
-        instruction: impl Into<String>,
-    ) -> Result<DeepResearchOperationResponse> {
-        let url = format!(
-            "{}/deepresearch/tasks/{}/update",
-            self.base_url,
-            task_id.as_ref()
-        );
+        status: &DeepResearchStatusResponse,
+        path: impl AsRef<Path>,
+    ) -> Result<PathBuf> {
+        let url = status.pdf_url.as_deref().ok_or_else(|| {
+            ValyuError::InvalidRequest(
+                "task has no PDF report; include OutputFormat::Pdf in its output formats"
+                    .to_string(),
+            )
+        })?;
 
-        let body = serde_json::json!({
-            "instruction": instruction.into()
-        });
+        self.download_file(url, path.as_ref().to_path_buf()).await
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Content-Type", "application/json")
-            .header("x-api-key", &self.api_key)
-            .json(&body)
-            .send()
-            .await?;
+    /// Download a file from an arbitrary URL to `path`
+    ///
+    /// The API key and default headers are not sent, since the URL is usually
+    /// on a third-party site.
+    ///
+    /// The client's timeout limits the wait for the response and each gap
+    /// between body chunks rather than the whole transfer, so large files
+    /// on slow links aren't cut off partway through.
+    async fn download_file(&self, url: &str, path: PathBuf) -> Result<PathBuf> {
+        let request = self.client.get(url).build()?;
+        let response = self.with_idle_timeout(self.execute(request)).await??;
 
         let status = response.status();
-
-        match status.as_u16() {
-            200 => {
-                // Success
-            }
-            401 | 403 => {
-                return Err(ValyuError::InvalidApiKey);
-            }
-            404 => {
-                return Err(ValyuError::ApiError("Task not found".to_string()));
-            }
-            429 => {
-                return Err(ValyuError::RateLimitExceeded);
-            }
-            _ => {
-                let error_text = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
-            }
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(ValyuError::HttpStatus {
+                status: status.as_u16(),
+                body,
+            });
         }
 
-        let update_response: DeepResearchOperationResponse =
-            response.json().await.map_err(|e| {
-                ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-            })?;
+        // Write chunks as they arrive so large files aren't held in memory
+        let mut response = response;
+        let mut file = tokio::fs::File::create(&path).await?;
+        while let Some(chunk) = self.with_idle_timeout(response.chunk()).await?? {
+            file.write_all(&chunk).await?;
+        }
+        file.flush().await?;
+        Ok(path)
+    }
 
-        Ok(update_response)
+    /// Run a step of a download, failing with [`ValyuError::Timeout`] if it
+    /// takes longer than the client's timeout
+    async fn with_idle_timeout<T>(&self, step: impl Future<Output = T>) -> Result<T> {
+        match self.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, step)
+                .await
+                .map_err(|_| ValyuError::Timeout),
+            None => Ok(step.await),
+        }
     }
 
-    /// Cancel a running task
+    /// Get AI-powered answers with automatic source retrieval
+    ///
+    /// Ask questions and get comprehensive answers backed by relevant sources.
     ///
     /// # Example
     ///
     /// ```no_run
-    /// use valyu::ValyuClient;
+    /// use valyu::{ValyuClient, AnswerRequest};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
-    ///     client.deepresearch_cancel("task-id").await?;
+    ///
+    ///     let request = AnswerRequest::new("What are the latest developments in quantum computing?")
+    ///         .with_search_type("web")
+    ///         .with_system_instructions("Focus on breakthroughs from 2024");
+    ///
+    ///     let response = client.answer(&request).await?;
+    ///
+    ///     if let Some(contents) = &response.contents {
+    ///         println!("Answer: {}", contents);
+    ///     }
+    ///
+    ///     if let Some(sources) = &response.search_results {
+    ///         println!("\nSources ({}):", sources.len());
+    ///         for source in sources {
+    ///             println!("  - {}", source.title.as_deref().unwrap_or("Untitled"));
+    ///         }
+    ///     }
     ///     Ok(())
     /// }
     /// ```
-    pub async fn deepresearch_cancel(
-        &self,
-        task_id: impl AsRef<str>,
-    ) -> Result<DeepResearchOperationResponse> {
-        let url = format!(
-            "{}/deepresearch/tasks/{}/cancel",
-            self.base_url,
-            task_id.as_ref()
-        );
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The request fails validation (see [`AnswerRequest::validate`])
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The request set [`structured_output`](AnswerRequest::with_structured_output)
+    ///   but the API answered with unstructured text, e.g. a refusal
+    ///   ([`ValyuError::ApiError`] containing the text)
+    /// - The response cannot be parsed
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.answer",
+            skip_all,
+            fields(
+                endpoint = "answer",
+                query_len = request.query.len(),
+                status = tracing::field::Empty,
+                tx_id = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn answer(&self, request: &AnswerRequest) -> Result<AnswerResponse> {
+        self.with_error_context("answer", Some(&request.query), async {
+            let response = self.answer_response(request).await?;
+            let rate_limit = rate_limit_info(response.headers());
+
+            let value = self.read_json(response).await?;
+            // Fails with the API's error message and code if `success` is false
+            let mut answer_response: AnswerResponse = parse_api_value(value)?;
+            answer_response.rate_limit = rate_limit;
+
+            // Logged before the structured output check below, since the call
+            // is billed even when that check fails
+            #[cfg(feature = "tracing")]
+            {
+                tracing::Span::current().record("tx_id", answer_response.ai_tx_id.as_deref());
+                log_cost(
+                    "answer",
+                    answer_response.ai_tx_id.as_deref(),
+                    answer_response.cost_dollars(),
+                );
+            }
+
+            let unstructured = answer_response.data_type.as_deref() == Some("unstructured");
+            if request.structured_output.is_some() && unstructured {
+                return Err(ValyuError::ApiError {
+                    message: format!(
+                        "structured output was requested but the answer is unstructured: {}",
+                        answer_response.answer_text().unwrap_or_default()
+                    ),
+                    code: None,
+                });
+            }
+
+            Ok(answer_response)
+        })
+        .await
+    }
+
+    /// Send an answer request and map error statuses, leaving the body unread
+    async fn answer_response(&self, request: &AnswerRequest) -> Result<reqwest::Response> {
+        request.validate()?;
 
-        let response = self
+        let url = format!("{}/answer", self.base_url_for(Endpoint::Answer));
+
+        let builder = self
             .client
             .post(&url)
-            .header("x-api-key", &self.api_key)
-            .send()
-            .await?;
+            .header("Content-Type", "application/json")
+            .json(request);
+
+        let response = self.send(builder, request.timeout).await?;
 
         let status = response.status();
 
+        // Handle specific HTTP status codes
         match status.as_u16() {
             200 => {
-                // Success
+                // Success - continue to parse response
+            }
+            400 => {
+                let error_text = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Invalid request parameters".to_string());
+                return Err(ValyuError::InvalidRequest(error_text));
             }
             401 | 403 => {
                 return Err(ValyuError::InvalidApiKey);
             }
-            404 => {
-                return Err(ValyuError::ApiError("Task not found".to_string()));
+            402 => {
+                return Err(ValyuError::InsufficientCredits);
             }
             429 => {
-                return Err(ValyuError::RateLimitExceeded);
+                return Err(ValyuError::RateLimitExceeded {
+                    retry_after: retry_after(response.headers()),
+                });
+            }
+            503 => {
+                return Err(ValyuError::ServiceUnavailable);
             }
             _ => {
-                let error_text = response
+                let body = response
                     .text()
                     .await
                     .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
+                return Err(ValyuError::HttpStatus {
+                    status: status.as_u16(),
+                    body,
+                });
             }
         }
 
-        let cancel_response: DeepResearchOperationResponse =
-            response.json().await.map_err(|e| {
-                ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-            })?;
+        Ok(response)
+    }
 
-        Ok(cancel_response)
+    /// Like [`answer`](Self::answer), but return the untyped JSON body
+    ///
+    /// Useful for inspecting fields the typed response doesn't model yet,
+    /// while keeping the client's authentication and retry handling.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{AnswerRequest, ValyuClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let request = AnswerRequest::new("What is quantum computing?");
+    ///     let raw = client.answer_raw(&request).await?;
+    ///     println!("{}", serde_json::to_string_pretty(&raw)?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`answer`](Self::answer), except that a
+    /// `success: false` body is returned as-is instead of as
+    /// [`ValyuError::ApiError`].
+    pub async fn answer_raw(&self, request: &AnswerRequest) -> Result<serde_json::Value> {
+        self.with_error_context("answer", Some(&request.query), async {
+            let response = self.answer_response(request).await?;
+            self.read_json(response).await
+        })
+        .await
     }
 
-    /// Delete a task
+    /// Convenience method to get an answer with default settings
+    ///
+    /// This is equivalent to creating an `AnswerRequest` with just a query
+    /// and calling `answer`.
     ///
     /// # Example
     ///
@@ -912,63 +1637,29 @@ impl ValyuClient {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
-    ///     client.deepresearch_delete("task-id").await?;
+    ///     let response = client.ask("What is quantum computing?").await?;
+    ///
+    ///     if let Some(contents) = &response.contents {
+    ///         println!("Answer: {}", contents);
+    ///     }
     ///     Ok(())
     /// }
     /// ```
-    pub async fn deepresearch_delete(
-        &self,
-        task_id: impl AsRef<str>,
-    ) -> Result<DeepResearchOperationResponse> {
-        let url = format!(
-            "{}/deepresearch/tasks/{}/delete",
-            self.base_url,
-            task_id.as_ref()
-        );
-
-        let response = self
-            .client
-            .delete(&url)
-            .header("x-api-key", &self.api_key)
-            .send()
-            .await?;
-
-        let status = response.status();
-
-        match status.as_u16() {
-            200 => {
-                // Success
-            }
-            401 | 403 => {
-                return Err(ValyuError::InvalidApiKey);
-            }
-            404 => {
-                return Err(ValyuError::ApiError("Task not found".to_string()));
-            }
-            429 => {
-                return Err(ValyuError::RateLimitExceeded);
-            }
-            _ => {
-                let error_text = response
-                    .text()
-                    .await
-                    .unwrap_or_else(|_| "Unknown error".to_string());
-                return Err(ValyuError::ApiError(format!(
-                    "HTTP {}: {}",
-                    status, error_text
-                )));
-            }
-        }
-
-        let delete_response: DeepResearchOperationResponse =
-            response.json().await.map_err(|e| {
-                ValyuError::ParseError(format!("Failed to parse API response: {}", e))
-            })?;
-
-        Ok(delete_response)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn ask(&self, query: impl Into<String>) -> Result<AnswerResponse> {
+        let request = AnswerRequest::new(query);
+        self.answer(&request).await
     }
 
-    /// Convenience method to create a research task with default settings
+    /// Get just the answer text for a query, using default settings
+    ///
+    /// A shortcut for [`ask`](Self::ask) when sources and cost don't matter.
     ///
     /// # Example
     ///
@@ -978,35 +1669,2592 @@ impl ValyuClient {
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = ValyuClient::new("your-api-key");
-    ///     let task = client.research("What is quantum computing?").await?;
-    ///     println!("Task ID: {:?}", task.deepresearch_id);
+    ///     let answer = client.ask_text("What is quantum computing?").await?;
+    ///     println!("{}", answer);
     ///     Ok(())
     /// }
     /// ```
-    pub async fn research(
-        &self,
-        query: impl Into<String>,
-    ) -> Result<DeepResearchCreateResponse> {
-        let request = DeepResearchCreateRequest::new(query);
-        self.deepresearch_create(&request).await
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`ask`](Self::ask), or
+    /// [`ValyuError::ParseError`] if the response has no text answer, e.g.
+    /// because it came back as structured JSON.
+    pub async fn ask_text(&self, query: impl Into<String>) -> Result<String> {
+        let response = self.ask(query).await?;
+        response.answer_text().map(str::to_string).ok_or_else(|| {
+            ValyuError::ParseError("Answer response did not contain a text answer".to_string())
+        })
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    // ========== DeepResearch API Methods ==========
+
+    /// Create a new DeepResearch task
+    ///
+    /// Starts an asynchronous research task that performs comprehensive research
+    /// across multiple sources and generates detailed reports.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{ValyuClient, DeepResearchCreateRequest, DeepResearchMode};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///
+    ///     let request = DeepResearchCreateRequest::new("What are the key differences between RAG and fine-tuning?")
+    ///         .with_mode(DeepResearchMode::Standard)
+    ///         .with_output_formats(vec!["markdown".to_string()]);
+    ///
+    ///     let response = client.deepresearch_create(&request).await?;
+    ///     println!("Task created: {:?}", response.deepresearch_id);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.deepresearch_create",
+            skip_all,
+            fields(
+                endpoint = "deepresearch/tasks",
+                query_len = request.input.len(),
+                status = tracing::field::Empty,
+                tx_id = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn deepresearch_create(
+        &self,
+        request: &DeepResearchCreateRequest,
+    ) -> Result<DeepResearchCreateResponse> {
+        self.with_error_context("deepresearch/tasks", Some(&request.input), async {
+            request.validate()?;
+
+            let url = format!(
+                "{}/deepresearch/tasks",
+                self.base_url_for(Endpoint::DeepResearch)
+            );
+
+            let mut builder = self
+                .client
+                .post(&url)
+                .header("Content-Type", "application/json")
+                .json(request);
+
+            // Generated once, so every retry below carries the same key
+            let idempotency_key = request.idempotency_key.clone().or_else(|| {
+                (self.retry_policy.max_retries > 0).then(|| uuid::Uuid::new_v4().to_string())
+            });
+            if let Some(key) = idempotency_key {
+                builder = builder.header("Idempotency-Key", key);
+            }
+
+            let response = self.send(builder, request.timeout).await?;
+
+            let status = response.status();
+
+            match status.as_u16() {
+                200..=202 => {
+                    // Success - continue to parse response
+                }
+                400 => {
+                    let error_text = response
+                        .text()
+                        .await
+                        .unwrap_or_else(|_| "Invalid request parameters".to_string());
+                    return Err(ValyuError::InvalidRequest(error_text));
+                }
+                401 | 403 => {
+                    return Err(ValyuError::InvalidApiKey);
+                }
+                402 => {
+                    return Err(ValyuError::InsufficientCredits);
+                }
+                413 => {
+                    return Err(ValyuError::PayloadTooLarge);
+                }
+                429 => {
+                    return Err(ValyuError::RateLimitExceeded {
+                        retry_after: retry_after(response.headers()),
+                    });
+                }
+                503 => {
+                    return Err(ValyuError::ServiceUnavailable);
+                }
+                _ => {
+                    let body = response
+                        .text()
+                        .await
+                        .unwrap_or_else(|_| "Unknown error".to_string());
+                    return Err(ValyuError::HttpStatus {
+                        status: status.as_u16(),
+                        body,
+                    });
+                }
+            }
+
+            let rate_limit = rate_limit_info(response.headers());
+            let value = self.read_json(response).await?;
+            // Fails with the API's error message and code if `success` is false
+            let mut create_response: DeepResearchCreateResponse = parse_api_value(value)?;
+            create_response.rate_limit = rate_limit;
+
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("tx_id", create_response.deepresearch_id.as_deref());
+
+            Ok(create_response)
+        })
+        .await
+    }
+
+    /// Get the status of a DeepResearch task
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let status = client.deepresearch_status("task-id-here").await?;
+    ///     println!("Status: {:?}", status.status);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.deepresearch_status",
+            skip_all,
+            fields(
+                endpoint = "deepresearch/tasks/status",
+                task_id = task_id.as_ref(),
+                status = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn deepresearch_status(
+        &self,
+        task_id: impl AsRef<str>,
+    ) -> Result<DeepResearchStatusResponse> {
+        let task_id = task_id.as_ref();
+
+        self.with_error_context(
+            &format!("deepresearch/tasks/{}/status", task_id),
+            None,
+            async {
+                let response = self.deepresearch_status_response(task_id).await?;
+                let rate_limit = rate_limit_info(response.headers());
+
+                let value = self.read_json(response).await?;
+                let mut status_response: DeepResearchStatusResponse = parse_value(value)?;
+                status_response.rate_limit = rate_limit;
+
+                Ok(status_response)
+            },
+        )
+        .await
+    }
+
+    /// Send a DeepResearch status request and map error statuses, leaving the body unread
+    async fn deepresearch_status_response(&self, task_id: &str) -> Result<reqwest::Response> {
+        let url = format!(
+            "{}/deepresearch/tasks/{}/status",
+            self.base_url_for(Endpoint::DeepResearch),
+            task_id
+        );
+
+        let builder = self.client.get(&url);
+
+        let response = self.send(builder, None).await?;
+
+        let status = response.status();
+
+        match status.as_u16() {
+            200 => {
+                // Success
+            }
+            401 | 403 => {
+                return Err(ValyuError::InvalidApiKey);
+            }
+            402 => {
+                return Err(ValyuError::InsufficientCredits);
+            }
+            404 => {
+                return Err(ValyuError::NotFound(task_id.to_string()));
+            }
+            429 => {
+                return Err(ValyuError::RateLimitExceeded {
+                    retry_after: retry_after(response.headers()),
+                });
+            }
+            503 => {
+                return Err(ValyuError::ServiceUnavailable);
+            }
+            _ => {
+                let body = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(ValyuError::HttpStatus {
+                    status: status.as_u16(),
+                    body,
+                });
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Like [`deepresearch_status`](Self::deepresearch_status), but return the untyped JSON body
+    ///
+    /// Useful for inspecting fields the typed response doesn't model yet,
+    /// while keeping the client's authentication and retry handling.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let raw = client.deepresearch_status_raw("task-id").await?;
+    ///     println!("{}", serde_json::to_string_pretty(&raw)?);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`deepresearch_status`](Self::deepresearch_status), except that a
+    /// `success: false` body is returned as-is instead of as
+    /// [`ValyuError::ApiError`].
+    pub async fn deepresearch_status_raw(
+        &self,
+        task_id: impl AsRef<str>,
+    ) -> Result<serde_json::Value> {
+        let task_id = task_id.as_ref();
+
+        self.with_error_context(
+            &format!("deepresearch/tasks/{}/status", task_id),
+            None,
+            async {
+                let response = self.deepresearch_status_response(task_id).await?;
+                self.read_json(response).await
+            },
+        )
+        .await
+    }
+
+    /// Wait for a DeepResearch task to complete
+    ///
+    /// Polls the task status until it completes, fails, or is cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `task_id` - The task identifier
+    /// * `poll_interval_secs` - Seconds between status checks; see
+    ///   [`DeepResearchMode::default_poll_interval_secs`](crate::DeepResearchMode::default_poll_interval_secs)
+    /// * `max_wait_secs` - Maximum seconds to wait; see
+    ///   [`DeepResearchMode::default_timeout_secs`](crate::DeepResearchMode::default_timeout_secs)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let result = client.deepresearch_wait("task-id", 5, 900).await?;
+    ///
+    ///     if let Some(output) = &result.output {
+    ///         println!("Research output: {}", output);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::WaitTimeout`] with the task's last status if it
+    /// is still queued or running after `max_wait_secs`, and
+    /// [`ValyuError::ApiError`] if the task fails or is cancelled.
+    pub async fn deepresearch_wait(
+        &self,
+        task_id: impl AsRef<str>,
+        poll_interval_secs: u64,
+        max_wait_secs: u64,
+    ) -> Result<DeepResearchStatusResponse> {
+        self.deepresearch_wait_with_progress(task_id, poll_interval_secs, max_wait_secs, |_| {})
+            .await
+    }
+
+    /// Create a DeepResearch task and wait for it to finish
+    ///
+    /// Combines [`deepresearch_create`](Self::deepresearch_create) and
+    /// [`deepresearch_wait`](Self::deepresearch_wait), giving up after the
+    /// request mode's [`default_timeout_secs`](crate::DeepResearchMode::default_timeout_secs).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{DeepResearchCreateRequest, DeepResearchMode, ValyuClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let request = DeepResearchCreateRequest::new("What is quantum computing?")
+    ///         .with_mode(DeepResearchMode::Fast);
+    ///
+    ///     let result = client.deepresearch_run(&request, 5).await?;
+    ///     if let Some(output) = &result.output {
+    ///         println!("Research output: {}", output);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::ParseError`] if the create response has no
+    /// `deepresearch_id`, or any error the create and wait calls return.
+    pub async fn deepresearch_run(
+        &self,
+        request: &DeepResearchCreateRequest,
+        poll_interval_secs: u64,
+    ) -> Result<DeepResearchStatusResponse> {
+        let task = self.deepresearch_create(request).await?;
+        let task_id = task.deepresearch_id.ok_or_else(|| {
+            ValyuError::ParseError(
+                "DeepResearch create response has no deepresearch_id".to_string(),
+            )
+        })?;
+
+        let max_wait_secs = request
+            .model
+            .clone()
+            .unwrap_or_default()
+            .default_timeout_secs();
+        self.deepresearch_wait(task_id, poll_interval_secs, max_wait_secs)
+            .await
+    }
+
+    /// Run a DeepResearch task to completion and return only its deliverables
+    ///
+    /// Behaves like [`deepresearch_run`](Self::deepresearch_run), for
+    /// workflows that only need the generated files.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{DeepResearchCreateRequest, Deliverable, ValyuClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let request = DeepResearchCreateRequest::new("Top 20 AI startups by funding")
+    ///         .with_deliverables(vec![Deliverable::csv("Startups with funding totals")]);
+    ///
+    ///     for file in client.deepresearch_deliverables(&request, 5).await? {
+    ///         println!("{:?}: {:?}", file.title, file.url);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::ApiError`] if the task produced no deliverables
+    /// or any deliverable failed, or any error
+    /// [`deepresearch_run`](Self::deepresearch_run) returns.
+    pub async fn deepresearch_deliverables(
+        &self,
+        request: &DeepResearchCreateRequest,
+        poll_interval_secs: u64,
+    ) -> Result<Vec<DeliverableResult>> {
+        let status = self.deepresearch_run(request, poll_interval_secs).await?;
+        let deliverables = status.deliverables.unwrap_or_default();
+
+        if deliverables.is_empty() {
+            return Err(ValyuError::ApiError {
+                message: "Task produced no deliverables".to_string(),
+                code: None,
+            });
+        }
+
+        if let Some(failed) = deliverables
+            .iter()
+            .find(|d| d.status == Some(DeliverableStatus::Failed))
+        {
+            return Err(ValyuError::ApiError {
+                message: format!(
+                    "Deliverable {} failed: {}",
+                    failed.title.as_deref().unwrap_or("(untitled)"),
+                    failed.error.as_deref().unwrap_or("unknown error")
+                ),
+                code: None,
+            });
+        }
+
+        Ok(deliverables)
+    }
+
+    /// Wait for a DeepResearch task to complete, reporting progress on each poll
+    ///
+    /// Behaves like [`deepresearch_wait`](Self::deepresearch_wait), but calls
+    /// `on_progress` after every status check that includes progress information.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let result = client
+    ///         .deepresearch_wait_with_progress("task-id", 5, 900, |progress| {
+    ///             println!("Step {}/{}", progress.current_step, progress.total_steps);
+    ///         })
+    ///         .await?;
+    ///
+    ///     println!("Status: {:?}", result.status);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn deepresearch_wait_with_progress(
+        &self,
+        task_id: impl AsRef<str>,
+        poll_interval_secs: u64,
+        max_wait_secs: u64,
+        on_progress: impl FnMut(&DeepResearchProgress),
+    ) -> Result<DeepResearchStatusResponse> {
+        let poll_duration = Duration::from_secs(poll_interval_secs);
+        self.poll_until_finished(task_id.as_ref(), max_wait_secs, on_progress, || {
+            poll_duration
+        })
+        .await
+    }
+
+    /// Wait for a DeepResearch task to complete, randomizing each poll delay
+    ///
+    /// Behaves like [`deepresearch_wait`](Self::deepresearch_wait), but each
+    /// delay is drawn uniformly from `poll_interval_secs` ± `jitter_fraction`
+    /// of it, so that many workers polling on the same schedule drift apart
+    /// instead of hitting the API in lockstep.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     // Poll every 4-6 seconds
+    ///     let result = client.deepresearch_wait_with_jitter("task-id", 5, 0.2, 900).await?;
+    ///     println!("Status: {:?}", result.status);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if `jitter_fraction` is not
+    /// within 0.0-1.0, or any error [`deepresearch_wait`](Self::deepresearch_wait)
+    /// returns.
+    pub async fn deepresearch_wait_with_jitter(
+        &self,
+        task_id: impl AsRef<str>,
+        poll_interval_secs: u64,
+        jitter_fraction: f64,
+        max_wait_secs: u64,
+    ) -> Result<DeepResearchStatusResponse> {
+        if !(0.0..=1.0).contains(&jitter_fraction) {
+            return Err(ValyuError::InvalidRequest(format!(
+                "jitter_fraction must be between 0.0 and 1.0, got {}",
+                jitter_fraction
+            )));
+        }
+
+        let next_delay = || jittered(Duration::from_secs(poll_interval_secs), jitter_fraction);
+        self.poll_until_finished(task_id.as_ref(), max_wait_secs, |_| {}, next_delay)
+            .await
+    }
+
+    /// Poll a task's status until it finishes, sleeping `next_delay()` between polls
+    async fn poll_until_finished(
+        &self,
+        task_id: &str,
+        max_wait_secs: u64,
+        mut on_progress: impl FnMut(&DeepResearchProgress),
+        mut next_delay: impl FnMut() -> Duration,
+    ) -> Result<DeepResearchStatusResponse> {
+        let start = std::time::Instant::now();
+        let max_duration = Duration::from_secs(max_wait_secs);
+
+        loop {
+            let status = self.deepresearch_status(task_id).await?;
+
+            if let Some(progress) = &status.progress {
+                on_progress(progress);
+            }
+
+            match &status.status {
+                Some(DeepResearchStatus::Completed) => {
+                    #[cfg(feature = "tracing")]
+                    log_cost(
+                        "deepresearch",
+                        status.deepresearch_id.as_deref(),
+                        status.usage.as_ref().and_then(|usage| usage.cost_dollars()),
+                    );
+                    return Ok(status);
+                }
+                Some(DeepResearchStatus::Failed) => {
+                    let error_msg = status
+                        .error
+                        .clone()
+                        .unwrap_or_else(|| "Task failed".to_string());
+                    return Err(ValyuError::ApiError {
+                        message: error_msg,
+                        code: None,
+                    });
+                }
+                Some(DeepResearchStatus::Cancelled) => {
+                    return Err(ValyuError::ApiError {
+                        message: "Task was cancelled".to_string(),
+                        code: None,
+                    });
+                }
+                _ => {
+                    // Still queued or running
+                    let elapsed = start.elapsed();
+                    if elapsed > max_duration {
+                        return Err(ValyuError::WaitTimeout {
+                            task_id: task_id.to_string(),
+                            // A task without a status yet hasn't started
+                            last_status: status.status.unwrap_or(DeepResearchStatus::Queued),
+                            elapsed,
+                        });
+                    }
+                    tokio::time::sleep(next_delay()).await;
+                }
+            }
+        }
+    }
+
+    /// Wait for a DeepResearch task to complete, giving up when `cancel` fires
+    ///
+    /// Behaves like [`deepresearch_wait`](Self::deepresearch_wait), but returns
+    /// as soon as the token is cancelled, aborting any in-flight status request
+    /// or poll delay. Only the wait is cancelled; the task keeps running on the
+    /// server unless you also call
+    /// [`deepresearch_cancel`](Self::deepresearch_cancel).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{CancellationToken, ValyuClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let cancel = CancellationToken::new();
+    ///
+    ///     // Hand a clone to whatever decides the result is no longer needed
+    ///     let on_navigate_away = cancel.clone();
+    ///     # drop(on_navigate_away);
+    ///
+    ///     let result = client
+    ///         .deepresearch_wait_cancellable("task-id", 5, 900, cancel)
+    ///         .await?;
+    ///     println!("Status: {:?}", result.status);
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::Cancelled`] if the token is cancelled before the
+    /// task finishes, or any error [`deepresearch_wait`](Self::deepresearch_wait)
+    /// returns.
+    pub async fn deepresearch_wait_cancellable(
+        &self,
+        task_id: impl AsRef<str>,
+        poll_interval_secs: u64,
+        max_wait_secs: u64,
+        cancel: CancellationToken,
+    ) -> Result<DeepResearchStatusResponse> {
+        tokio::select! {
+            biased;
+            _ = cancel.cancelled() => Err(ValyuError::Cancelled),
+            result = self.deepresearch_wait(task_id, poll_interval_secs, max_wait_secs) => result,
+        }
+    }
+
+    /// Stream status snapshots of a DeepResearch task as it is polled
+    ///
+    /// Yields one snapshot per poll, waiting `poll_interval_secs` between polls.
+    /// The stream ends after yielding a `Completed`, `Failed`, or `Cancelled`
+    /// snapshot, or after the first error. Unlike
+    /// [`deepresearch_wait`](Self::deepresearch_wait) there is no maximum wait;
+    /// stop consuming the stream (or wrap it in a timeout) to give up early.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///
+    ///     let mut updates = std::pin::pin!(client.deepresearch_stream("task-id", 5));
+    ///     while let Some(status) = updates.next().await {
+    ///         let status = status?;
+    ///         println!("Status: {:?}, progress: {:?}", status.status, status.progress);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn deepresearch_stream(
+        &self,
+        task_id: impl Into<String>,
+        poll_interval_secs: u64,
+    ) -> impl Stream<Item = Result<DeepResearchStatusResponse>> + '_ {
+        let poll_duration = Duration::from_secs(poll_interval_secs);
+
+        // State is the task ID plus whether to sleep before the next poll;
+        // `None` ends the stream
+        stream::unfold(Some((task_id.into(), false)), move |state| async move {
+            let (task_id, sleep_first) = state?;
+            if sleep_first {
+                tokio::time::sleep(poll_duration).await;
+            }
+
+            match self.deepresearch_status(&task_id).await {
+                Ok(status) => {
+                    let finished = status.status.as_ref().is_some_and(|s| s.is_terminal());
+                    let next = if finished {
+                        None
+                    } else {
+                        Some((task_id, true))
+                    };
+                    Some((Ok(status), next))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        })
+    }
+
+    /// List DeepResearch tasks
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let tasks = client.deepresearch_list("api-key-id", Some(50)).await?;
+    ///
+    ///     if let Some(data) = &tasks.data {
+    ///         for task in data {
+    ///             println!("{}: {:?}", task.query, task.status);
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn deepresearch_list(
+        &self,
+        api_key_id: impl AsRef<str>,
+        limit: Option<u32>,
+    ) -> Result<DeepResearchListResponse> {
+        let limit = limit.map(|l| l.to_string());
+
+        let mut params = vec![("api_key_id", api_key_id.as_ref())];
+        if let Some(l) = &limit {
+            params.push(("limit", l));
+        }
+
+        self.deepresearch_list_with(&params).await
+    }
+
+    /// List DeepResearch tasks, passing arbitrary query parameters
+    ///
+    /// Parameters are URL-encoded and sent as-is, so filters the API supports
+    /// but [`deepresearch_list`](Self::deepresearch_list) doesn't expose can
+    /// still be used.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let tasks = client
+    ///         .deepresearch_list_with(&[("api_key_id", "api-key-id"), ("limit", "10")])
+    ///         .await?;
+    ///     println!("{} tasks", tasks.data.map_or(0, |data| data.len()));
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if the client's base URL is not
+    /// a valid URL, or the same errors as
+    /// [`deepresearch_list`](Self::deepresearch_list).
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.deepresearch_list",
+            skip_all,
+            fields(
+                endpoint = "deepresearch/list",
+                status = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn deepresearch_list_with(
+        &self,
+        params: &[(&str, &str)],
+    ) -> Result<DeepResearchListResponse> {
+        self.with_error_context("deepresearch/list", None, async {
+            let base_url = self.base_url_for(Endpoint::DeepResearch);
+            let list_url = format!("{}/deepresearch/list", base_url);
+            let mut url = reqwest::Url::parse(&list_url).map_err(|e| {
+                ValyuError::InvalidRequest(format!("invalid base URL {}: {}", base_url, e))
+            })?;
+            url.query_pairs_mut().extend_pairs(params);
+
+            let builder = self.client.get(url);
+
+            let response = self.send(builder, None).await?;
+
+            let status = response.status();
+
+            match status.as_u16() {
+                200 => {
+                    // Success
+                }
+                401 | 403 => {
+                    return Err(ValyuError::InvalidApiKey);
+                }
+                402 => {
+                    return Err(ValyuError::InsufficientCredits);
+                }
+                429 => {
+                    return Err(ValyuError::RateLimitExceeded {
+                        retry_after: retry_after(response.headers()),
+                    });
+                }
+                _ => {
+                    let body = response
+                        .text()
+                        .await
+                        .unwrap_or_else(|_| "Unknown error".to_string());
+                    return Err(ValyuError::HttpStatus {
+                        status: status.as_u16(),
+                        body,
+                    });
+                }
+            }
+
+            let rate_limit = rate_limit_info(response.headers());
+            let value = self.read_json(response).await?;
+            let mut list_response: DeepResearchListResponse = parse_value(value)?;
+            list_response.rate_limit = rate_limit;
+
+            Ok(list_response)
+        })
+        .await
+    }
+
+    /// Add follow-up instructions to a running task
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     client.deepresearch_update("task-id", "Focus more on peer-reviewed sources").await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn deepresearch_update(
+        &self,
+        task_id: impl AsRef<str>,
+        instruction: impl Into<String>,
+    ) -> Result<DeepResearchOperationResponse> {
+        let body = serde_json::json!({
+            "instruction": instruction.into()
+        });
+
+        self.deepresearch_update_with(task_id, body).await
+    }
+
+    /// Update a running task with an arbitrary request body
+    ///
+    /// The body is posted as-is, so update options the API supports but
+    /// [`deepresearch_update`](Self::deepresearch_update) doesn't expose can
+    /// still be used.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let body = serde_json::json!({
+    ///         "instruction": "Also cover the EU market",
+    ///         "urls": ["https://example.com/eu-report"]
+    ///     });
+    ///     client.deepresearch_update_with("task-id", body).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.deepresearch_update",
+            skip_all,
+            fields(
+                endpoint = "deepresearch/tasks/update",
+                task_id = task_id.as_ref(),
+                status = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn deepresearch_update_with(
+        &self,
+        task_id: impl AsRef<str>,
+        body: serde_json::Value,
+    ) -> Result<DeepResearchOperationResponse> {
+        let task_id = task_id.as_ref();
+
+        self.with_error_context(
+            &format!("deepresearch/tasks/{}/update", task_id),
+            None,
+            async {
+                let url = format!(
+                    "{}/deepresearch/tasks/{}/update",
+                    self.base_url_for(Endpoint::DeepResearch),
+                    task_id
+                );
+
+                let builder = self
+                    .client
+                    .post(&url)
+                    .header("Content-Type", "application/json")
+                    .json(&body);
+
+                let response = self.send(builder, None).await?;
+
+                let status = response.status();
+
+                match status.as_u16() {
+                    200 => {
+                        // Success
+                    }
+                    401 | 403 => {
+                        return Err(ValyuError::InvalidApiKey);
+                    }
+                    402 => {
+                        return Err(ValyuError::InsufficientCredits);
+                    }
+                    404 => {
+                        return Err(ValyuError::NotFound(task_id.to_string()));
+                    }
+                    429 => {
+                        return Err(ValyuError::RateLimitExceeded {
+                            retry_after: retry_after(response.headers()),
+                        });
+                    }
+                    _ => {
+                        let body = response
+                            .text()
+                            .await
+                            .unwrap_or_else(|_| "Unknown error".to_string());
+                        return Err(ValyuError::HttpStatus {
+                            status: status.as_u16(),
+                            body,
+                        });
+                    }
+                }
+
+                let rate_limit = rate_limit_info(response.headers());
+                let value = self.read_json(response).await?;
+                let mut update_response: DeepResearchOperationResponse = parse_value(value)?;
+                update_response.rate_limit = rate_limit;
+
+                Ok(update_response)
+            },
+        )
+        .await
+    }
+
+    /// Cancel a running task
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     client.deepresearch_cancel("task-id").await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.deepresearch_cancel",
+            skip_all,
+            fields(
+                endpoint = "deepresearch/tasks/cancel",
+                task_id = task_id.as_ref(),
+                status = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn deepresearch_cancel(
+        &self,
+        task_id: impl AsRef<str>,
+    ) -> Result<DeepResearchOperationResponse> {
+        let task_id = task_id.as_ref();
+
+        self.with_error_context(
+            &format!("deepresearch/tasks/{}/cancel", task_id),
+            None,
+            async {
+                let url = format!(
+                    "{}/deepresearch/tasks/{}/cancel",
+                    self.base_url_for(Endpoint::DeepResearch),
+                    task_id
+                );
+
+                let builder = self.client.post(&url);
+
+                let response = self.send(builder, None).await?;
+
+                let status = response.status();
+
+                match status.as_u16() {
+                    200 => {
+                        // Success
+                    }
+                    401 | 403 => {
+                        return Err(ValyuError::InvalidApiKey);
+                    }
+                    402 => {
+                        return Err(ValyuError::InsufficientCredits);
+                    }
+                    404 => {
+                        return Err(ValyuError::NotFound(task_id.to_string()));
+                    }
+                    429 => {
+                        return Err(ValyuError::RateLimitExceeded {
+                            retry_after: retry_after(response.headers()),
+                        });
+                    }
+                    _ => {
+                        let body = response
+                            .text()
+                            .await
+                            .unwrap_or_else(|_| "Unknown error".to_string());
+                        return Err(ValyuError::HttpStatus {
+                            status: status.as_u16(),
+                            body,
+                        });
+                    }
+                }
+
+                let rate_limit = rate_limit_info(response.headers());
+                let value = self.read_json(response).await?;
+                let mut cancel_response: DeepResearchOperationResponse = parse_value(value)?;
+                cancel_response.rate_limit = rate_limit;
+
+                Ok(cancel_response)
+            },
+        )
+        .await
+    }
+
+    /// Delete a task
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     client.deepresearch_delete("task-id").await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "valyu.deepresearch_delete",
+            skip_all,
+            fields(
+                endpoint = "deepresearch/tasks/delete",
+                task_id = task_id.as_ref(),
+                status = tracing::field::Empty,
+            )
+        )
+    )]
+    pub async fn deepresearch_delete(
+        &self,
+        task_id: impl AsRef<str>,
+    ) -> Result<DeepResearchOperationResponse> {
+        let task_id = task_id.as_ref();
+
+        self.with_error_context(
+            &format!("deepresearch/tasks/{}/delete", task_id),
+            None,
+            async {
+                let url = format!(
+                    "{}/deepresearch/tasks/{}/delete",
+                    self.base_url_for(Endpoint::DeepResearch),
+                    task_id
+                );
+
+                let builder = self.client.delete(&url);
+
+                let response = self.send(builder, None).await?;
+
+                let status = response.status();
+
+                match status.as_u16() {
+                    200 => {
+                        // Success
+                    }
+                    401 | 403 => {
+                        return Err(ValyuError::InvalidApiKey);
+                    }
+                    402 => {
+                        return Err(ValyuError::InsufficientCredits);
+                    }
+                    404 => {
+                        return Err(ValyuError::NotFound(task_id.to_string()));
+                    }
+                    429 => {
+                        return Err(ValyuError::RateLimitExceeded {
+                            retry_after: retry_after(response.headers()),
+                        });
+                    }
+                    _ => {
+                        let body = response
+                            .text()
+                            .await
+                            .unwrap_or_else(|_| "Unknown error".to_string());
+                        return Err(ValyuError::HttpStatus {
+                            status: status.as_u16(),
+                            body,
+                        });
+                    }
+                }
+
+                let rate_limit = rate_limit_info(response.headers());
+                let value = self.read_json(response).await?;
+                let mut delete_response: DeepResearchOperationResponse = parse_value(value)?;
+                delete_response.rate_limit = rate_limit;
+
+                Ok(delete_response)
+            },
+        )
+        .await
+    }
+
+    /// Convenience method to create a research task with default settings
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let task = client.research("What is quantum computing?").await?;
+    ///     println!("Task ID: {:?}", task.deepresearch_id);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn research(
+        &self,
+        query: impl Into<String>,
+    ) -> Result<DeepResearchCreateResponse> {
+        let request = DeepResearchCreateRequest::new(query);
+        self.deepresearch_create(&request).await
+    }
+
+    /// Start a research task that builds on a previous one
+    ///
+    /// Creates a task with default settings and `previous_reports` set to
+    /// `previous_id`, so the new research can refine or extend the earlier
+    /// report. Use [`DeepResearchCreateRequest::with_previous_reports`] to
+    /// customize the request or build on several reports.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let task = client
+    ///         .deepresearch_follow_up("task-id", "Now focus on European regulation")
+    ///         .await?;
+    ///     println!("Follow-up task ID: {:?}", task.deepresearch_id);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn deepresearch_follow_up(
+        &self,
+        previous_id: impl Into<String>,
+        input: impl Into<String>,
+    ) -> Result<DeepResearchCreateResponse> {
+        let request =
+            DeepResearchCreateRequest::new(input).with_previous_reports(vec![previous_id.into()]);
+        self.deepresearch_create(&request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{mock_client, MockResponse, MockTransport};
+
+    #[test]
+    fn test_client_creation() {
+        let client = ValyuClient::new("test-key");
+        assert_eq!(client.api_key, "test-key");
+        assert_eq!(client.base_url, API_BASE_URL);
+        assert_eq!(client.timeout, Some(DEFAULT_TIMEOUT));
+
+        let client = ValyuClient::with_client("test-key", reqwest::Client::new());
+        assert_eq!(client.timeout, None);
+    }
+
+    #[test]
+    fn test_with_api_key() {
+        let shared = ValyuClient::builder()
+            .api_key("default-key")
+            .base_url("https://custom.url")
+            .retry_policy(RetryPolicy::new(2))
+            .capture_raw(true)
+            .build()
+            .unwrap();
+        *shared.last_raw_response.as_ref().unwrap().lock().unwrap() =
+            Some(serde_json::json!({ "tenant": "default" }));
+
+        let tenant = shared.with_api_key("tenant-key");
+        assert_eq!(tenant.api_key, "tenant-key");
+        assert_eq!(tenant.base_url, "https://custom.url");
+        assert_eq!(tenant.retry_policy, shared.retry_policy);
+        assert!(Arc::ptr_eq(&tenant.transport, &shared.transport));
+        assert_eq!(tenant.last_raw_response(), None);
+        assert_eq!(shared.api_key, "default-key");
+    }
+
+    #[test]
+    fn test_client_with_custom_url() {
+        let client = ValyuClient::with_base_url("test-key", "https://custom.url");
+        assert_eq!(client.api_key, "test-key");
+        assert_eq!(client.base_url, "https://custom.url");
+    }
+
+    #[test]
+    fn test_jittered_stays_within_fraction() {
+        let delay = Duration::from_secs(10);
+        for _ in 0..100 {
+            let jittered = jittered(delay, 0.2);
+            assert!(jittered >= Duration::from_secs(8) && jittered <= Duration::from_secs(12));
+        }
+        assert_eq!(jittered(delay, 0.0), delay);
+    }
+
+    #[test]
+    fn test_debug_redacts_api_key() {
+        let api_key = "vk-secret-0123456789abcd";
+        let client = ValyuClient::with_base_url(api_key, "https://custom.url");
+        let debug = format!("{:?}", client);
+
+        assert!(!debug.contains(api_key));
+        assert!(!debug.contains("secret"));
+        assert!(debug.contains("...abcd"));
+        assert!(debug.contains("https://custom.url"));
+
+        let debug = format!("{:?}", ValyuClient::builder().api_key(api_key));
+        assert!(!debug.contains(api_key));
+    }
+
+    #[test]
+    fn test_client_from_env() {
+        let client = ValyuClient::from_lookup(|name| match name {
+            "VALYU_API_KEY" => Some("env-key".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(client.api_key, "env-key");
+        assert_eq!(client.base_url, API_BASE_URL);
+
+        let client = ValyuClient::from_lookup(|name| match name {
+            "VALYU_API_KEY" => Some("env-key".to_string()),
+            "VALYU_BASE_URL" => Some("https://custom.url".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(client.base_url, "https://custom.url");
+    }
+
+    #[test]
+    fn test_client_from_env_missing_key() {
+        let result = ValyuClient::from_lookup(|_| None);
+        assert!(matches!(result, Err(ValyuError::MissingApiKey)));
+
+        let result = ValyuClient::from_lookup(|_| Some("  ".to_string()));
+        assert!(matches!(result, Err(ValyuError::MissingApiKey)));
+    }
+
+    #[test]
+    fn test_retry_after_seconds() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "120".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn test_retry_after_http_date() {
+        let later = SystemTime::now() + Duration::from_secs(300);
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            httpdate::fmt_http_date(later).parse().unwrap(),
+        );
+
+        let wait = retry_after(&headers).unwrap();
+        assert!(wait <= Duration::from_secs(300));
+        assert!(wait >= Duration::from_secs(298));
+
+        // Dates in the past mean "retry now"
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Sun, 06 Nov 1994 08:49:37 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_retry_after_missing_or_invalid() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(reqwest::header::RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_rate_limit_display() {
+        let err = ValyuError::RateLimitExceeded {
+            retry_after: Some(Duration::from_secs(30)),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Rate limit exceeded (retry after 30 seconds)"
+        );
+
+        let err = ValyuError::RateLimitExceeded { retry_after: None };
+        assert_eq!(err.to_string(), "Rate limit exceeded");
+    }
+
+    /// Serve one canned HTTP response per connection and return the server's base URL
+    async fn mock_server(responses: Vec<(u16, &'static str)>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+
+                // Read the full request so the client never sees a reset
+                let mut request = Vec::new();
+                let mut buf = [0u8; 4096];
+                loop {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                    let text = String::from_utf8_lossy(&request);
+                    if let Some(end) = text.find("\r\n\r\n") {
+                        let content_length = text[..end]
+                            .lines()
+                            .find_map(|line| {
+                                let (name, value) = line.split_once(':')?;
+                                name.eq_ignore_ascii_case("content-length")
+                                    .then(|| value.trim().parse::<usize>().ok())?
+                            })
+                            .unwrap_or(0);
+                        if request.len() >= end + 4 + content_length {
+                            break;
+                        }
+                    }
+                    if n == 0 {
+                        break;
+                    }
+                }
+
+                let response = format!(
+                    "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nRetry-After: 0\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_retries_rate_limits() {
+        let base_url = mock_server(vec![
+            (429, "{}"),
+            (503, "{}"),
+            (200, r#"{"success": true, "results": []}"#),
+        ])
+        .await;
+
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .base_url(base_url)
+            .retry_policy(RetryPolicy::new(2).with_initial_backoff(Duration::from_millis(1)))
+            .build()
+            .unwrap();
+
+        let response = client.search("test").await.unwrap();
+        assert!(response.success);
+    }
+
+    #[tokio::test]
+    async fn test_wait_reports_progress() {
+        let base_url = mock_server(vec![
+            (200, r#"{"success": true, "status": "queued"}"#),
+            (
+                200,
+                r#"{"success": true, "status": "running", "progress": {"current_step": 1, "total_steps": 3}}"#,
+            ),
+            (
+                200,
+                r#"{"success": true, "status": "completed", "progress": {"current_step": 3, "total_steps": 3}}"#,
+            ),
+        ])
+        .await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let mut steps = Vec::new();
+        let result = client
+            .deepresearch_wait_with_progress("task-id", 0, 60, |progress| {
+                steps.push((progress.current_step, progress.total_steps));
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.status, Some(DeepResearchStatus::Completed));
+        assert_eq!(steps, vec![(1, 3), (3, 3)]);
+    }
+
+    #[tokio::test]
+    async fn test_verify_api_key() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                404,
+                serde_json::json!({ "error": "Task not found" }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true }),
+            ))
+            .with_response(MockResponse::new(401, "{}"))
+            .with_response(MockResponse::new(403, "{}"))
+            .with_response(MockResponse::new(503, "{}"))
+            .with_response(MockResponse::new(500, "{}"));
+        let client = mock_client(transport.clone());
+
+        for _ in 0..2 {
+            assert!(client.verify_api_key().await.is_ok());
+        }
+        for _ in 0..2 {
+            assert!(matches!(
+                client.verify_api_key().await,
+                Err(ValyuError::InvalidApiKey)
+            ));
+        }
+        assert!(matches!(
+            client.verify_api_key().await,
+            Err(ValyuError::ServiceUnavailable)
+        ));
+        assert!(matches!(
+            client.verify_api_key().await,
+            Err(ValyuError::HttpStatus { status: 500, .. })
+        ));
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, "GET");
+        assert!(request
+            .url
+            .ends_with("/deepresearch/tasks/valyu-sdk-key-check/status"));
+        assert_eq!(request.headers["x-api-key"], "test-key");
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_jitter() {
+        let base_url = mock_server(vec![
+            (200, r#"{"success": true, "status": "running"}"#),
+            (200, r#"{"success": true, "status": "completed"}"#),
+        ])
+        .await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let result = client
+            .deepresearch_wait_with_jitter("task-id", 0, 0.5, 60)
+            .await
+            .unwrap();
+        assert_eq!(result.status, Some(DeepResearchStatus::Completed));
+
+        assert!(matches!(
+            client
+                .deepresearch_wait_with_jitter("task-id", 5, 1.5, 60)
+                .await,
+            Err(ValyuError::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_timeout_reports_last_status() {
+        let base_url = mock_server(vec![(200, r#"{"success": true, "status": "running"}"#)]).await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        match client.deepresearch_wait("task-id", 0, 0).await {
+            Err(ValyuError::WaitTimeout {
+                task_id,
+                last_status,
+                ..
+            }) => {
+                assert_eq!(task_id, "task-id");
+                assert_eq!(last_status, DeepResearchStatus::Running);
+            }
+            other => panic!("expected WaitTimeout, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_batch_preserves_order_and_errors() {
+        let base_url = mock_server(vec![
+            (200, r#"{"success": true, "tx_id": "first"}"#),
+            (401, "{}"),
+            (200, r#"{"success": true, "tx_id": "third"}"#),
+        ])
+        .await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let requests: Vec<_> = ["a", "b", "c"]
+            .into_iter()
+            .map(DeepSearchRequest::new)
+            .collect();
+        let results = client.search_batch(&requests, 1).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().tx_id.as_deref(), Some("first"));
+        assert!(matches!(results[1], Err(ValyuError::InvalidApiKey)));
+        assert_eq!(results[2].as_ref().unwrap().tx_id.as_deref(), Some("third"));
+    }
+
+    #[tokio::test]
+    async fn test_search_batch_try() {
+        let requests: Vec<_> = ["a", "b", "c"]
+            .into_iter()
+            .map(DeepSearchRequest::new)
+            .collect();
+        let ok = |tx_id: &str| {
+            MockResponse::json(200, serde_json::json!({ "success": true, "tx_id": tx_id }))
+        };
+
+        let transport = MockTransport::new()
+            .with_response(ok("first"))
+            .with_response(ok("second"))
+            .with_response(ok("third"));
+        let client = mock_client(transport);
+        let responses = client.search_batch_try(&requests, 1).await.unwrap();
+        let tx_ids: Vec<_> = responses
+            .iter()
+            .map(|r| r.tx_id.as_deref().unwrap())
+            .collect();
+        assert_eq!(tx_ids, ["first", "second", "third"]);
+
+        let transport = MockTransport::new()
+            .with_response(ok("first"))
+            .with_response(MockResponse::new(401, "{}"));
+        let client = mock_client(transport.clone());
+        assert!(matches!(
+            client.search_batch_try(&requests, 1).await,
+            Err(ValyuError::InvalidApiKey)
+        ));
+        assert_eq!(transport.requests().len(), 2);
+
+        let transport = MockTransport::new()
+            .with_response(MockResponse::new(503, "{}"))
+            .with_response(ok("second"))
+            .with_response(ok("third"));
+        let client = mock_client(transport.clone());
+        assert!(matches!(
+            client.search_batch_try(&requests, 1).await,
+            Err(ValyuError::ServiceUnavailable)
+        ));
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_search_until_budget() {
+        let transport = MockTransport::new();
+        for _ in 0..4 {
+            transport.push_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "total_deduction_dollars": 0.1 }),
+            ));
+        }
+        let client = mock_client(transport.clone());
+
+        let requests = ["a", "b", "c", "d"].into_iter().map(DeepSearchRequest::new);
+        let run = client.search_until_budget(requests, 0.25).await.unwrap();
+
+        // A third search at the observed $0.10 would exceed the budget
+        assert_eq!(run.responses.len(), 2);
+        assert!((run.spent_usd - 0.2).abs() < 1e-9);
+        assert!(run.error.is_none());
+        assert_eq!(transport.requests().len(), 2);
+
+        let requests = ["a"].into_iter().map(DeepSearchRequest::new);
+        let run = client.search_until_budget(requests, 0.0).await.unwrap();
+        assert!(run.responses.is_empty());
+        assert_eq!(run.spent_usd, 0.0);
+
+        let requests = ["a"].into_iter().map(DeepSearchRequest::new);
+        assert!(matches!(
+            client.search_until_budget(requests, f64::NAN).await,
+            Err(ValyuError::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_search_until_budget_keeps_spend_on_error() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "total_deduction_dollars": 0.1 }),
+            ))
+            .with_response(MockResponse::new(401, "unauthorized"));
+        let client = mock_client(transport.clone());
+
+        let requests = ["a", "b", "c"].into_iter().map(DeepSearchRequest::new);
+        let run = client.search_until_budget(requests, 1.0).await.unwrap();
+
+        assert_eq!(run.responses.len(), 1);
+        assert!((run.spent_usd - 0.1).abs() < 1e-9);
+        assert!(matches!(run.error, Some(ValyuError::InvalidApiKey)));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_override() {
+        // Accept the connection but never respond
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+
+        let client = ValyuClient::with_base_url("test-key", base_url);
+        let request = DeepSearchRequest::new("test").with_timeout(Duration::from_millis(50));
+
+        let err = client.deep_search(&request).await.unwrap_err();
+        assert!(matches!(err, ValyuError::Timeout));
+    }
+
+    #[tokio::test]
+    async fn test_download_outlasts_client_timeout() {
+        use tokio::io::AsyncWriteExt;
+
+        // Stream the body slowly: each gap is under the timeout, the total is not
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/report.pdf", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 4\r\n\r\n")
+                .await
+                .unwrap();
+            for byte in [b"a", b"b", b"c", b"d"] {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                socket.write_all(byte).await.unwrap();
+            }
+        });
+
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .timeout(Duration::from_millis(250))
+            .build()
+            .unwrap();
+        let path = std::env::temp_dir().join(format!("valyu-slow-{}.pdf", std::process::id()));
+
+        client.download_file(&url, path.clone()).await.unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "abcd");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unhandled_status_is_structured() {
+        let base_url = mock_server(vec![(502, "bad gateway")]).await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let err = client.search("test").await.unwrap_err();
+        assert_eq!(err.to_string(), "HTTP 502: bad gateway");
+        assert!(matches!(
+            err,
+            ValyuError::HttpStatus { status: 502, ref body } if body == "bad gateway"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_missing_task_is_not_found() {
+        let base_url = mock_server(vec![(404, "{}")]).await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let err = client
+            .deepresearch_status("missing-task")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ValyuError::NotFound(ref id) if id == "missing-task"));
+    }
+
+    #[tokio::test]
+    async fn test_payment_required_is_insufficient_credits() {
+        let base_url = mock_server(vec![(402, "{}"), (402, "{}")]).await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let err = client.ask("test").await.unwrap_err();
+        assert!(matches!(err, ValyuError::InsufficientCredits));
+
+        let err = client.search("test").await.unwrap_err();
+        assert!(matches!(err, ValyuError::InsufficientCredits));
+    }
+
+    #[tokio::test]
+    async fn test_search_bad_request_is_invalid_request() {
+        let base_url = mock_server(vec![(400, r#"{"error": "max_num_results too large"}"#)]).await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let err = client.search("test").await.unwrap_err();
+        assert!(
+            matches!(err, ValyuError::InvalidRequest(ref body) if body.contains("max_num_results"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_payload_too_large() {
+        let base_url = mock_server(vec![(413, "{}"), (413, "{}")]).await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let err = client.content("https://example.com").await.unwrap_err();
+        assert!(matches!(err, ValyuError::PayloadTooLarge));
+        assert!(err.to_string().contains("fewer URLs"));
+
+        let err = client.research("test").await.unwrap_err();
+        assert!(matches!(err, ValyuError::PayloadTooLarge));
+    }
+
+    #[tokio::test]
+    async fn test_deepresearch_list_encodes_params() {
+        let list = serde_json::json!({ "success": true, "data": [] });
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(200, list.clone()))
+            .with_response(MockResponse::json(200, list));
+        let client = mock_client(transport.clone());
+
+        client
+            .deepresearch_list("key&id=1 #x", Some(5))
+            .await
+            .unwrap();
+        client
+            .deepresearch_list_with(&[("api_key_id", "a/b"), ("status", "completed")])
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert!(requests[0]
+            .url
+            .ends_with("/deepresearch/list?api_key_id=key%26id%3D1+%23x&limit=5"));
+        assert!(requests[1]
+            .url
+            .ends_with("/deepresearch/list?api_key_id=a%2Fb&status=completed"));
+    }
+
+    #[tokio::test]
+    async fn test_deepresearch_update_bodies() {
+        let ok = serde_json::json!({ "success": true });
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(200, ok.clone()))
+            .with_response(MockResponse::json(200, ok));
+        let client = mock_client(transport.clone());
+
+        client
+            .deepresearch_update("task-1", "Focus on Europe")
+            .await
+            .unwrap();
+        let body =
+            serde_json::json!({ "instruction": "Add sources", "urls": ["https://example.com"] });
+        client
+            .deepresearch_update_with("task-1", body.clone())
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert!(requests[0]
+            .url
+            .ends_with("/deepresearch/tasks/task-1/update"));
+        assert_eq!(
+            requests[0].json().unwrap(),
+            serde_json::json!({ "instruction": "Focus on Europe" })
+        );
+        assert_eq!(requests[1].json().unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn test_unsuccessful_body_exposes_error_code() {
+        let base_url = mock_server(vec![
+            (
+                200,
+                r#"{"success": false, "error": "Query too long", "code": "query_too_long"}"#,
+            ),
+            (200, r#"{"success": false}"#),
+        ])
+        .await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let err = client.search("test").await.unwrap_err();
+        assert!(matches!(
+            err,
+            ValyuError::ApiError { ref message, code: Some(ref code) }
+                if message == "Query too long" && code == "query_too_long"
+        ));
+
+        let err = client.search("test").await.unwrap_err();
+        assert!(matches!(err, ValyuError::ApiError { code: None, .. }));
+    }
 
     #[test]
-    fn test_client_creation() {
-        let client = ValyuClient::new("test-key");
-        assert_eq!(client.api_key, "test-key");
-        assert_eq!(client.base_url, API_BASE_URL);
+    fn test_unsuccessful_body_with_numeric_code() {
+        let err = parse_api_value::<DeepSearchResponse>(serde_json::json!({
+            "success": false, "error": "Too many requests", "code": 429
+        }))
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ValyuError::ApiError { ref message, code: Some(ref code) }
+                if message == "Too many requests" && code == "429"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_deepresearch_stream_ends_at_terminal_status() {
+        let base_url = mock_server(vec![
+            (200, r#"{"success": true, "status": "queued"}"#),
+            (200, r#"{"success": true, "status": "running"}"#),
+            (
+                200,
+                r#"{"success": true, "status": "failed", "error": "boom"}"#,
+            ),
+        ])
+        .await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let statuses: Vec<_> = client
+            .deepresearch_stream("task-id", 0)
+            .map(|status| status.unwrap().status.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(
+            statuses,
+            vec![
+                DeepResearchStatus::Queued,
+                DeepResearchStatus::Running,
+                DeepResearchStatus::Failed,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_no_retries_by_default() {
+        let base_url = mock_server(vec![(429, "{}")]).await;
+        let client = ValyuClient::with_base_url("test-key", base_url);
+
+        let err = client.search("test").await.unwrap_err();
+        assert!(matches!(
+            err,
+            ValyuError::RateLimitExceeded {
+                retry_after: Some(Duration::ZERO)
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_headers_on_success() {
+        let transport = MockTransport::new()
+            .with_response(
+                MockResponse::json(200, serde_json::json!({ "success": true, "results": [] }))
+                    .with_header("X-RateLimit-Remaining", "7")
+                    .with_header("X-RateLimit-Reset", "1767225600")
+                    .with_header("X-RateLimit-Limit", "100"),
+            )
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "status": "running" }),
+            ));
+        let client = mock_client(transport);
+
+        let response = client.search("test").await.unwrap();
+        assert_eq!(
+            response.rate_limit,
+            Some(RateLimitInfo {
+                remaining: Some(7),
+                reset_at: Some(1_767_225_600),
+                limit: Some(100),
+            })
+        );
+
+        let status = client.deepresearch_status("task-id").await.unwrap();
+        assert_eq!(status.rate_limit, None);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_headers_on_list_and_operations() {
+        let transport = MockTransport::new()
+            .with_response(
+                MockResponse::json(200, serde_json::json!({ "success": true, "data": [] }))
+                    .with_header("X-RateLimit-Remaining", "3"),
+            )
+            .with_response(
+                MockResponse::json(200, serde_json::json!({ "success": true }))
+                    .with_header("X-RateLimit-Remaining", "2"),
+            )
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true }),
+            ));
+        let client = mock_client(transport);
+
+        let list = client.deepresearch_list("key-id", None).await.unwrap();
+        assert_eq!(list.rate_limit.and_then(|info| info.remaining), Some(3));
+
+        let cancel = client.deepresearch_cancel("task-id").await.unwrap();
+        assert_eq!(cancel.rate_limit.and_then(|info| info.remaining), Some(2));
+
+        let delete = client.deepresearch_delete("task-id").await.unwrap();
+        assert_eq!(delete.rate_limit, None);
+    }
+
+    #[tokio::test]
+    async fn test_search_without_results_field_is_empty() {
+        let transport = MockTransport::new().with_response(MockResponse::json(
+            200,
+            serde_json::json!({ "success": true }),
+        ));
+        let client = mock_client(transport);
+
+        let response = client.search("nothing matches this").await.unwrap();
+        assert_eq!(response.results.map(|results| results.len()), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_records_requests() {
+        let transport = MockTransport::new().with_response(MockResponse::json(
+            200,
+            serde_json::json!({ "success": true, "results": [] }),
+        ));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .base_url("https://mock.valyu")
+            .default_header("X-Tenant-Id", "acme")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let response = client.search("test").await.unwrap();
+        assert!(response.success);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "https://mock.valyu/deepsearch");
+        assert_eq!(requests[0].headers["x-api-key"], "test-key");
+        assert_eq!(requests[0].headers["x-tenant-id"], "acme");
+        assert_eq!(requests[0].json().unwrap()["query"], "test");
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_base_override() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "results": [] }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "data": [] }),
+            ));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .base_url("https://mock.valyu")
+            .endpoint_base(Endpoint::DeepResearch, "https://research.valyu")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        client.search("test").await.unwrap();
+        let _ = client.deepresearch_list("key", None).await;
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].url, "https://mock.valyu/deepsearch");
+        assert!(requests[1]
+            .url
+            .starts_with("https://research.valyu/deepresearch/list"));
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency_queues_requests() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Debug, Default)]
+        struct SlowTransport {
+            in_flight: AtomicUsize,
+            peak: AtomicUsize,
+        }
+
+        impl Transport for SlowTransport {
+            fn execute(&self, _request: reqwest::Request) -> crate::TransportFuture<'_> {
+                Box::pin(async move {
+                    let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    self.peak.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                    let body = r#"{"success": true, "results": []}"#;
+                    Ok(http::Response::new(body).into())
+                })
+            }
+        }
+
+        let transport = Arc::new(SlowTransport::default());
+        let client = ValyuClient {
+            transport: transport.clone(),
+            ..ValyuClient::builder()
+                .api_key("test-key")
+                .max_concurrency(2)
+                .build()
+                .unwrap()
+        };
+
+        let results = futures_util::future::join_all((0..6).map(|_| client.search("test"))).await;
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert_eq!(transport.peak.load(Ordering::SeqCst), 2);
+
+        let result = ValyuClient::builder()
+            .api_key("test-key")
+            .max_concurrency(0)
+            .build();
+        assert!(matches!(result, Err(ValyuError::InvalidRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency_frees_slot_during_backoff() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::new(429, "{}").with_header("Retry-After", "1"))
+            .with_response(MockResponse::new(200, "other call"))
+            .with_response(MockResponse::new(200, "retried"));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .transport(transport)
+            .max_concurrency(1)
+            .retry_policy(RetryPolicy::new(1))
+            .build()
+            .unwrap();
+        let request = || client.client.get("https://example.com");
+
+        let backing_off = async {
+            let response = client.send(request(), None).await.unwrap();
+            response.text().await.unwrap()
+        };
+        let during_backoff = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let send = client.send(request(), None);
+            let response = tokio::time::timeout(Duration::from_millis(500), send)
+                .await
+                .expect("call was blocked by the backing-off request")
+                .unwrap();
+            response.text().await.unwrap()
+        };
+
+        let (first, second) = tokio::join!(backing_off, during_backoff);
+        assert_eq!(first, "retried");
+        assert_eq!(second, "other call");
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency_holds_slot_until_body_is_read() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::new(200, "first"))
+            .with_response(MockResponse::new(200, "second"));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .transport(transport)
+            .max_concurrency(1)
+            .build()
+            .unwrap();
+        let request = || client.client.get("https://example.com").build().unwrap();
+
+        let first = client.execute(request()).await.unwrap();
+        let waiting = tokio::time::timeout(Duration::from_millis(50), client.execute(request()));
+        assert!(waiting.await.is_err());
+
+        assert_eq!(first.text().await.unwrap(), "first");
+        let second = client.execute(request()).await.unwrap();
+        assert_eq!(second.text().await.unwrap(), "second");
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_retries() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::new(503, "{}"))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "results": [] }),
+            ));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .retry_policy(RetryPolicy::new(1).with_initial_backoff(Duration::ZERO))
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        assert!(client.search("test").await.unwrap().success);
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_ask_text() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "contents": "42", "data_type": "unstructured" }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "contents": { "answer": 42 }, "data_type": "structured" }),
+            ));
+        let client = mock_client(transport);
+
+        assert_eq!(client.ask_text("question").await.unwrap(), "42");
+        assert!(matches!(
+            client.ask_text("question").await,
+            Err(ValyuError::ParseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_content() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({
+                    "success": true,
+                    "results": [{ "url": "https://example.com", "content": "full text" }]
+                }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "results": [] }),
+            ));
+        let client = mock_client(transport.clone());
+
+        let result: SearchResult =
+            serde_json::from_value(serde_json::json!({ "url": "https://example.com" })).unwrap();
+        let content = client.fetch_content(&result).await.unwrap();
+        assert_eq!(content.content_as_string().as_deref(), Some("full text"));
+        assert_eq!(
+            transport.requests()[0].json().unwrap()["urls"],
+            serde_json::json!(["https://example.com"])
+        );
+
+        assert!(matches!(
+            client.fetch_content(&result).await,
+            Err(ValyuError::NotFound(url)) if url == "https://example.com"
+        ));
+
+        let no_url: SearchResult = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert!(matches!(
+            client.fetch_content(&no_url).await,
+            Err(ValyuError::InvalidRequest(_))
+        ));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_content() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({
+                    "success": true,
+                    "results": [{ "url": "https://example.com", "content": "full text" }]
+                }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "results": [] }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": false, "error": "Failed to fetch URL" }),
+            ));
+        let client = mock_client(transport.clone());
+
+        let content = client.content("https://example.com").await.unwrap();
+        assert_eq!(content.content_as_string().as_deref(), Some("full text"));
+        assert_eq!(
+            transport.requests()[0].json().unwrap()["urls"],
+            serde_json::json!(["https://example.com"])
+        );
+
+        assert!(matches!(
+            client.content("https://example.com").await,
+            Err(ValyuError::NotFound(url)) if url == "https://example.com"
+        ));
+        assert!(matches!(
+            client.content("https://example.com").await,
+            Err(ValyuError::ApiError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_partial_content_is_flagged() {
+        let body = serde_json::json!({ "success": true, "results": [] });
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(206, body.clone()))
+            .with_response(MockResponse::json(200, body.clone()))
+            .with_response(MockResponse::json(206, body));
+        let client = mock_client(transport);
+
+        let response = client.search("test").await.unwrap();
+        assert_eq!(response.http_status, Some(206));
+        assert!(response.is_partial());
+        assert!(!client.search("test").await.unwrap().is_partial());
+
+        let request = ContentsRequest::new(vec!["https://example.com".to_string()]);
+        assert!(client.contents(&request).await.unwrap().is_partial());
     }
 
     #[test]
-    fn test_client_with_custom_url() {
-        let client = ValyuClient::with_base_url("test-key", "https://custom.url");
-        assert_eq!(client.api_key, "test-key");
-        assert_eq!(client.base_url, "https://custom.url");
+    fn test_image_file_names() {
+        let urls: Vec<String> = [
+            "https://example.com/img/photo.png",
+            "https://cdn.example.com/other/photo.png",
+            "https://example.com/",
+            "https://example.com/a%20b.jpg?size=large",
+            "not a url",
+        ]
+        .iter()
+        .map(|url| url.to_string())
+        .collect();
+
+        assert_eq!(
+            image_file_names(&urls),
+            vec![
+                "photo.png",
+                "2-photo.png",
+                "image-3",
+                "a_20b.jpg",
+                "image-5"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_images_reports_failures() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::new(200, "png-bytes"))
+            .with_response(MockResponse::new(404, "missing"));
+        let client = mock_client(transport.clone());
+
+        let result: ContentResult = serde_json::from_value(serde_json::json!({
+            "images": ["https://example.com/a.png", "https://example.com/b.png"]
+        }))
+        .unwrap();
+        let dir = std::env::temp_dir().join(format!("valyu-images-{}", std::process::id()));
+
+        let downloads = client.download_images(&result, &dir).await.unwrap();
+        assert_eq!(downloads.len(), 2);
+
+        let (url, outcome) = &downloads[0];
+        assert_eq!(url, "https://example.com/a.png");
+        let path = outcome.as_ref().unwrap();
+        assert_eq!(path, &dir.join("a.png"));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "png-bytes");
+
+        let (url, outcome) = &downloads[1];
+        assert_eq!(url, "https://example.com/b.png");
+        assert!(matches!(
+            outcome,
+            Err(ValyuError::HttpStatus { status: 404, .. })
+        ));
+        assert!(!dir.join("b.png").exists());
+        assert!(!transport.requests()[0].headers.contains_key("x-api-key"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_pdf() {
+        let transport = MockTransport::new().with_response(MockResponse::new(200, "%PDF-1.7"));
+        let client = mock_client(transport.clone());
+
+        let status: DeepResearchStatusResponse = serde_json::from_value(serde_json::json!({
+            "success": true, "pdf_url": "https://example.com/report.pdf"
+        }))
+        .unwrap();
+        let path = std::env::temp_dir().join(format!("valyu-report-{}.pdf", std::process::id()));
+
+        assert_eq!(client.download_pdf(&status, &path).await.unwrap(), path);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "%PDF-1.7");
+        assert_eq!(
+            transport.requests()[0].url,
+            "https://example.com/report.pdf"
+        );
+        std::fs::remove_file(&path).unwrap();
+
+        let no_pdf: DeepResearchStatusResponse =
+            serde_json::from_value(serde_json::json!({ "success": true })).unwrap();
+        assert!(matches!(
+            client.download_pdf(&no_pdf, &path).await,
+            Err(ValyuError::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_cancellable() {
+        let running = serde_json::json!({ "success": true, "status": "running" });
+        let transport = MockTransport::new().with_response(MockResponse::json(200, running));
+        let client = mock_client(transport.clone());
+
+        let cancel = CancellationToken::new();
+        let trigger = cancel.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            trigger.cancel();
+        });
+
+        // The long poll interval would block for a minute without cancellation
+        let result = client
+            .deepresearch_wait_cancellable("task-id", 60, 900, cancel)
+            .await;
+        assert!(matches!(result, Err(ValyuError::Cancelled)));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    // The typed call rejects `new_field` with strict parsing
+    #[cfg(not(feature = "strict-parsing"))]
+    #[tokio::test]
+    async fn test_raw_responses() {
+        let body = serde_json::json!({ "success": true, "results": [], "new_field": 1 });
+        let failed = serde_json::json!({ "success": false, "error": "bad query" });
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(200, body.clone()))
+            .with_response(MockResponse::json(200, failed.clone()))
+            .with_response(MockResponse::json(200, body.clone()));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .capture_raw(true)
+            .transport(transport)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.last_raw_response(), None);
+
+        let request = DeepSearchRequest::new("test");
+        assert_eq!(client.deep_search_raw(&request).await.unwrap(), body);
+        assert_eq!(client.deep_search_raw(&request).await.unwrap(), failed);
+
+        client.deep_search(&request).await.unwrap();
+        assert_eq!(client.last_raw_response(), Some(body));
+    }
+
+    #[tokio::test]
+    async fn test_deepresearch_run() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "deepresearch_id": "task-1", "status": "queued" }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "status": "completed", "output": "done" }),
+            ))
+            .with_response(MockResponse::json(200, serde_json::json!({ "success": true })));
+        let client = mock_client(transport.clone());
+        let request =
+            DeepResearchCreateRequest::new("test").with_mode(crate::DeepResearchMode::Fast);
+
+        let result = client.deepresearch_run(&request, 0).await.unwrap();
+        assert_eq!(result.output, Some(serde_json::json!("done")));
+        assert!(transport.requests()[1].url.contains("task-1"));
+
+        assert!(matches!(
+            client.deepresearch_run(&request, 0).await,
+            Err(ValyuError::ParseError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_deepresearch_deliverables() {
+        let created = serde_json::json!({ "success": true, "deepresearch_id": "task-1" });
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(200, created.clone()))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({
+                    "success": true,
+                    "status": "completed",
+                    "deliverables": [{ "type": "csv", "status": "completed", "url": "https://files/a.csv" }]
+                }),
+            ))
+            .with_response(MockResponse::json(200, created.clone()))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({
+                    "success": true,
+                    "status": "completed",
+                    "deliverables": [{ "type": "pdf", "status": "failed", "error": "render error" }]
+                }),
+            ))
+            .with_response(MockResponse::json(200, created))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "status": "completed" }),
+            ));
+        let client = mock_client(transport);
+        let request = DeepResearchCreateRequest::new("test");
+
+        let files = client.deepresearch_deliverables(&request, 0).await.unwrap();
+        assert_eq!(files[0].url.as_deref(), Some("https://files/a.csv"));
+
+        match client.deepresearch_deliverables(&request, 0).await {
+            Err(ValyuError::ApiError { message, .. }) => assert!(message.contains("render error")),
+            other => panic!("expected ApiError, got {:?}", other),
+        }
+        assert!(matches!(
+            client.deepresearch_deliverables(&request, 0).await,
+            Err(ValyuError::ApiError { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_error_context() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::new(401, "{}"))
+            .with_response(MockResponse::new(404, "{}"));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .error_context(true)
+            .transport(transport)
+            .build()
+            .unwrap();
+
+        let err = client.search("secret ".repeat(20)).await.unwrap_err();
+        match &err {
+            ValyuError::WithContext {
+                endpoint, query, ..
+            } => {
+                assert_eq!(endpoint, "deepsearch");
+                assert_eq!(query.as_deref(), Some("<redacted, 140 chars>"));
+            }
+            other => panic!("expected WithContext, got {:?}", other),
+        }
+        assert!(matches!(err.root(), ValyuError::InvalidApiKey));
+        assert!(!err.to_string().contains("secret"));
+
+        let err = client.deepresearch_status("task-1").await.unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("deepresearch/tasks/task-1/status: "));
+        assert!(matches!(err.root(), ValyuError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_error_context_raw_query() {
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .error_context(true)
+            .error_context_raw_query(true)
+            .transport(MockTransport::new().with_response(MockResponse::new(401, "{}")))
+            .build()
+            .unwrap();
+
+        let err = client.search("x".repeat(100)).await.unwrap_err();
+        let expected = format!("deepsearch (query \"{}...\"): ", "x".repeat(80));
+        assert!(err.to_string().starts_with(&expected), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_deepresearch_follow_up() {
+        let transport = MockTransport::new().with_response(MockResponse::json(
+            200,
+            serde_json::json!({ "success": true, "deepresearch_id": "task-2" }),
+        ));
+        let client = mock_client(transport.clone());
+
+        let task = client
+            .deepresearch_follow_up("task-1", "Go deeper")
+            .await
+            .unwrap();
+        assert_eq!(task.deepresearch_id.as_deref(), Some("task-2"));
+
+        let body = transport.requests()[0].json().unwrap();
+        assert_eq!(body["input"], "Go deeper");
+        assert_eq!(body["previousReports"], serde_json::json!(["task-1"]));
+    }
+
+    #[tokio::test]
+    async fn test_answer_structured_output_mismatch() {
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "data_type": "unstructured", "contents": "I can't help with that" }),
+            ))
+            .with_response(MockResponse::json(
+                200,
+                serde_json::json!({ "success": true, "data_type": "structured", "contents": { "summary": "ok" } }),
+            ));
+        let client = mock_client(transport);
+        let request = AnswerRequest::new("test")
+            .with_structured_output(serde_json::json!({ "type": "object" }));
+
+        match client.answer(&request).await {
+            Err(ValyuError::ApiError { message, .. }) => assert!(message.contains("I can't help")),
+            other => panic!("expected ApiError, got {:?}", other),
+        }
+        assert!(client.answer(&request).await.unwrap().is_structured());
+    }
+
+    #[tokio::test]
+    async fn test_bearer_auth_mode() {
+        let transport = MockTransport::new().with_response(MockResponse::json(
+            200,
+            serde_json::json!({ "success": true, "results": [] }),
+        ));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .auth_mode(AuthMode::Bearer)
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        client.search("test").await.unwrap();
+
+        let headers = &transport.requests()[0].headers;
+        assert_eq!(headers["authorization"], "Bearer test-key");
+        assert!(!headers.contains_key("x-api-key"));
+    }
+
+    #[tokio::test]
+    async fn test_deepresearch_create_idempotency_key() {
+        let created = serde_json::json!({ "success": true, "deepresearch_id": "task-1" });
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(200, created.clone()))
+            .with_response(MockResponse::new(503, "{}"))
+            .with_response(MockResponse::json(200, created.clone()))
+            .with_response(MockResponse::json(200, created));
+        let client = mock_client(transport.clone());
+
+        // No key without retries unless one is set
+        client.research("test").await.unwrap();
+        assert!(!transport.requests()[0]
+            .headers
+            .contains_key("idempotency-key"));
+
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .retry_policy(RetryPolicy::new(1).with_initial_backoff(Duration::ZERO))
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+        client.research("test").await.unwrap();
+        let requests = transport.requests();
+        let key = &requests[1].headers["idempotency-key"];
+        assert_eq!(key.len(), 36);
+        assert_eq!(&requests[2].headers["idempotency-key"], key);
+
+        let request = DeepResearchCreateRequest::new("test").with_idempotency_key("my-key");
+        client.deepresearch_create(&request).await.unwrap();
+        assert_eq!(transport.requests()[3].headers["idempotency-key"], "my-key");
+    }
+
+    #[tokio::test]
+    async fn test_default_tool_call() {
+        let ok = serde_json::json!({ "success": true, "results": [] });
+        let transport = MockTransport::new()
+            .with_response(MockResponse::json(200, ok.clone()))
+            .with_response(MockResponse::json(200, ok));
+        let client = ValyuClient::builder()
+            .api_key("test-key")
+            .default_tool_call(false)
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        client.search("test").await.unwrap();
+        client
+            .deep_search(DeepSearchRequest::new("test").with_is_tool_call(true))
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].json().unwrap()["is_tool_call"], false);
+        assert_eq!(requests[1].json().unwrap()["is_tool_call"], true);
     }
 }
@@ -0,0 +1,31 @@
+//! API endpoint groups that can be routed to their own host
+
+/// A group of API endpoints sharing a base URL
+///
+/// Used with [`ValyuClientBuilder::endpoint_base`](crate::ValyuClientBuilder::endpoint_base)
+/// to send some endpoints to a different host than the rest, e.g. in split or
+/// on-prem deployments.
+///
+/// # Example
+///
+/// ```
+/// use valyu::{Endpoint, ValyuClient};
+///
+/// let client = ValyuClient::builder()
+///     .api_key("your-api-key")
+///     .endpoint_base(Endpoint::DeepResearch, "https://research.internal.example/v1")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    /// `/deepsearch`, used by the search methods
+    DeepSearch,
+    /// `/contents`
+    Contents,
+    /// `/answer`
+    Answer,
+    /// Every `/deepresearch` route: creating, polling, listing, updating,
+    /// cancelling, and deleting tasks
+    DeepResearch,
+}
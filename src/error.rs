@@ -1,5 +1,9 @@
+use std::time::Duration;
+
 use thiserror::Error;
 
+use crate::types::DeepResearchStatus;
+
 /// Errors that can occur when using the Valyu SDK
 #[derive(Error, Debug)]
 pub enum ValyuError {
@@ -8,8 +12,22 @@ pub enum ValyuError {
     RequestFailed(#[from] reqwest::Error),
 
     /// API returned an error response
-    #[error("API error: {0}")]
-    ApiError(String),
+    #[error("API error: {message}{}", format_error_code(code))]
+    ApiError {
+        /// Human-readable error message
+        message: String,
+        /// Machine-readable error code, when the API provides one
+        code: Option<String>,
+    },
+
+    /// API returned an HTTP status the SDK has no dedicated variant for
+    #[error("HTTP {status}: {body}")]
+    HttpStatus {
+        /// HTTP status code
+        status: u16,
+        /// Response body, usually the API's error message
+        body: String,
+    },
 
     /// Failed to parse API response
     #[error("Failed to parse API response: {0}")]
@@ -19,18 +37,219 @@ pub enum ValyuError {
     #[error("Invalid API key provided")]
     InvalidApiKey,
 
+    /// The account does not have enough credits for the request
+    #[error("Insufficient credits: top up your balance at https://platform.valyu.ai")]
+    InsufficientCredits,
+
+    /// No API key was configured
+    #[error(
+        "No API key provided: set the VALYU_API_KEY environment variable or pass a key explicitly"
+    )]
+    MissingApiKey,
+
+    /// The requested resource (e.g. a DeepResearch task) does not exist
+    #[error("Not found: {0}")]
+    NotFound(String),
+
     /// Invalid request parameters
     #[error("Invalid request parameters: {0}")]
     InvalidRequest(String),
 
+    /// The request body was larger than the API accepts (HTTP 413)
+    #[error("Request payload too large: send fewer URLs or smaller file attachments, or split the work across several requests")]
+    PayloadTooLarge,
+
     /// Rate limit exceeded
-    #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    #[error("Rate limit exceeded{}", format_retry_after(retry_after))]
+    RateLimitExceeded {
+        /// How long to wait before retrying, taken from the `Retry-After` header
+        retry_after: Option<Duration>,
+    },
 
     /// Service unavailable
     #[error("Service unavailable")]
     ServiceUnavailable,
+
+    /// The request did not complete within its timeout
+    #[error("Request timed out")]
+    Timeout,
+
+    /// A DeepResearch task was still queued or running when the wait gave up
+    ///
+    /// The task keeps going on the server, so waiting again or checking its
+    /// status later may still return a result.
+    #[error(
+        "DeepResearch task {task_id} still {last_status:?} after waiting {} seconds",
+        elapsed.as_secs()
+    )]
+    WaitTimeout {
+        /// The task that was being waited on
+        task_id: String,
+        /// The task's status at the last poll ([`Queued`](DeepResearchStatus::Queued)
+        /// or [`Running`](DeepResearchStatus::Running))
+        last_status: DeepResearchStatus,
+        /// How long the wait lasted
+        elapsed: Duration,
+    },
+
+    /// The operation was cancelled through a cancellation token
+    #[error("Operation cancelled")]
+    Cancelled,
+
+    /// Reading or writing a local file failed
+    ///
+    /// Returned by file helpers such as
+    /// [`DeepResearchFileAttachment::from_path`](crate::DeepResearchFileAttachment::from_path)
+    /// and [`ValyuClient::download_images`](crate::ValyuClient::download_images);
+    /// network failures are reported as [`RequestFailed`](Self::RequestFailed).
+    #[error("File I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An error annotated with the API call that produced it
+    ///
+    /// Only returned by clients built with
+    /// [`ValyuClientBuilder::error_context`](crate::ValyuClientBuilder::error_context).
+    /// Use [`root`](Self::root) to match on the underlying error.
+    #[error("{endpoint}{}: {source}", format_query(query))]
+    WithContext {
+        /// The error that occurred
+        source: Box<ValyuError>,
+        /// API endpoint that was called, e.g. `"deepsearch"`
+        endpoint: String,
+        /// The query or URLs sent: only their length, e.g.
+        /// `<redacted, 42 chars>`, unless
+        /// [`error_context_raw_query`](crate::ValyuClientBuilder::error_context_raw_query)
+        /// is enabled, in which case their quoted start
+        query: Option<String>,
+    },
+}
+
+impl ValyuError {
+    /// The underlying error, with any [`WithContext`](Self::WithContext)
+    /// wrappers removed
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{ValyuClient, ValyuError};
+    ///
+    /// # async fn example(client: ValyuClient) {
+    /// if let Err(e) = client.search("quantum computing").await {
+    ///     eprintln!("{}", e);
+    ///     if matches!(e.root(), ValyuError::RateLimitExceeded { .. }) {
+    ///         // back off
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn root(&self) -> &ValyuError {
+        match self {
+            ValyuError::WithContext { source, .. } => source.root(),
+            other => other,
+        }
+    }
+
+    /// Whether the error is transient, so the same request may succeed if
+    /// retried later
+    ///
+    /// True for rate limiting, service unavailability, timeouts, and
+    /// connection failures; the client's [`RetryPolicy`](crate::RetryPolicy)
+    /// retries the same cases. [`WithContext`](Self::WithContext) wrappers are
+    /// classified by the error they contain.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+    /// let response = match client.search("quantum computing").await {
+    ///     Err(e) if e.is_retryable() => client.search("quantum computing").await?,
+    ///     other => other?,
+    /// };
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self.root() {
+            ValyuError::RateLimitExceeded { .. }
+            | ValyuError::ServiceUnavailable
+            | ValyuError::Timeout => true,
+            ValyuError::RequestFailed(e) => e.is_timeout() || e.is_connect(),
+            _ => false,
+        }
+    }
 }
 
 /// Result type alias for Valyu SDK operations
 pub type Result<T> = std::result::Result<T, ValyuError>;
+
+fn format_error_code(code: &Option<String>) -> String {
+    match code {
+        Some(code) => format!(" ({})", code),
+        None => String::new(),
+    }
+}
+
+fn format_retry_after(retry_after: &Option<Duration>) -> String {
+    match retry_after {
+        Some(wait) => format!(" (retry after {} seconds)", wait.as_secs()),
+        None => String::new(),
+    }
+}
+
+fn format_query(query: &Option<String>) -> String {
+    match query {
+        Some(query) => format!(" (query {})", query),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_retryable() {
+        let retryable = [
+            ValyuError::RateLimitExceeded { retry_after: None },
+            ValyuError::ServiceUnavailable,
+            ValyuError::Timeout,
+            ValyuError::WithContext {
+                source: Box::new(ValyuError::Timeout),
+                endpoint: "deepsearch".to_string(),
+                query: None,
+            },
+        ];
+        for error in retryable {
+            assert!(error.is_retryable(), "{:?}", error);
+        }
+
+        let permanent = [
+            ValyuError::InvalidApiKey,
+            ValyuError::InvalidRequest("bad".to_string()),
+            ValyuError::NotFound("task".to_string()),
+            ValyuError::InsufficientCredits,
+            ValyuError::PayloadTooLarge,
+            ValyuError::Cancelled,
+            ValyuError::WaitTimeout {
+                task_id: "task".to_string(),
+                last_status: DeepResearchStatus::Running,
+                elapsed: Duration::from_secs(60),
+            },
+        ];
+        for error in permanent {
+            assert!(!error.is_retryable(), "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn test_io_error_display() {
+        let err = ValyuError::from(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "no such file",
+        ));
+        assert!(matches!(err, ValyuError::Io(_)));
+        assert_eq!(err.to_string(), "File I/O error: no such file");
+    }
+}
@@ -91,7 +91,7 @@
 //!     match client.search("test").await {
 //!         Ok(response) => println!("Success!"),
 //!         Err(ValyuError::InvalidApiKey) => eprintln!("Invalid API key"),
-//!         Err(ValyuError::RateLimitExceeded) => eprintln!("Rate limit exceeded"),
+//!         Err(ValyuError::RateLimitExceeded { .. }) => eprintln!("Rate limit exceeded"),
 //!         Err(e) => eprintln!("Error: {}", e),
 //!     }
 //! }
@@ -100,24 +100,43 @@
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod builder;
 mod client;
+mod endpoint;
 mod error;
+mod retry;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+mod transport;
 mod types;
 
 // Re-export public API
+pub use auth::AuthMode;
+pub use builder::ValyuClientBuilder;
 pub use client::ValyuClient;
+pub use endpoint::Endpoint;
 pub use error::{Result, ValyuError};
+pub use retry::RetryPolicy;
+pub use tokio_util::sync::CancellationToken;
+pub use transport::{ReqwestTransport, Transport, TransportFuture};
 pub use types::{
+    // Shared
+    Cost, RateLimitInfo, SearchType, Source, sum_costs,
     // DeepSearch API
-    DeepSearchRequest, DeepSearchResponse, ResultsBySource, SearchResult,
+    BudgetedSearchResult, DeepSearchRequest, DeepSearchResponse, DeepSearchResponseLength,
+    ResultsBySource, SearchResult,
     // Contents API
-    ContentResult, ContentsRequest, ContentsResponse, ResponseLength, SummaryOption,
+    ContentResult, ContentsRequest, ContentsResponse, ExtractEffort, ResponseLength, SummaryOption,
     // Answer API
     AnswerCost, AnswerRequest, AnswerResponse, AnswerSearchMetadata, AnswerSearchResult, AiUsage,
     // DeepResearch API
     DeepResearchCreateRequest, DeepResearchCreateResponse, DeepResearchFileAttachment,
-    DeepResearchImage, DeepResearchListResponse, DeepResearchMCPServerConfig, DeepResearchMode,
-    DeepResearchOperationResponse, DeepResearchProgress, DeepResearchSearchConfig,
-    DeepResearchSource, DeepResearchStatus, DeepResearchStatusResponse, DeepResearchTaskListItem,
-    DeepResearchUsage,
+    DeepResearchImage, DeepResearchListResponse, DeepResearchMCPServerConfig, DeepResearchMessage,
+    DeepResearchMode, DeepResearchOperationResponse, DeepResearchProgress,
+    DeepResearchSearchConfig, DeepResearchSource, DeepResearchStatus, DeepResearchStatusResponse,
+    DeepResearchTaskListItem, DeepResearchUsage, Deliverable, DeliverableResult,
+    DeliverableStatus, DeliverableType, McpAuth, OutputFormat,
 };
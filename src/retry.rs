@@ -0,0 +1,113 @@
+//! Retry configuration for transient API failures
+
+use std::time::Duration;
+
+/// Policy for retrying requests that fail with a transient error
+///
+/// Rate limit (429) and service unavailable (503) responses, as well as
+/// connection and timeout errors, are retried up to `max_retries` times.
+/// Rate limited requests wait for the server's `Retry-After` hint when present;
+/// otherwise the delay doubles after every attempt, starting at
+/// `initial_backoff` and capped at `max_backoff`.
+///
+/// The default policy performs no retries.
+///
+/// # Example
+///
+/// ```
+/// use valyu::RetryPolicy;
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new(3)
+///     .with_initial_backoff(Duration::from_millis(250))
+///     .with_max_backoff(Duration::from_secs(10));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt
+    pub max_retries: u32,
+
+    /// Delay before the first retry
+    pub initial_backoff: Duration,
+
+    /// Upper bound for the delay between retries
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// Create a policy that retries up to `max_retries` times with default backoff
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::RetryPolicy;
+    ///
+    /// let policy = RetryPolicy::new(3);
+    /// assert_eq!(policy.max_retries, 3);
+    /// ```
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            ..Self::default()
+        }
+    }
+
+    /// Create a policy that never retries
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    /// Set the delay before the first retry
+    pub fn with_initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the upper bound for the delay between retries
+    pub fn with_max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Delay to wait before the given retry attempt (0-based)
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let policy = RetryPolicy::new(5)
+            .with_initial_backoff(Duration::from_secs(1))
+            .with_max_backoff(Duration::from_secs(5));
+
+        assert_eq!(policy.backoff(0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(1), Duration::from_secs(2));
+        assert_eq!(policy.backoff(2), Duration::from_secs(4));
+        assert_eq!(policy.backoff(3), Duration::from_secs(5));
+        assert_eq!(policy.backoff(40), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_default_does_not_retry() {
+        assert_eq!(RetryPolicy::default().max_retries, 0);
+        assert_eq!(RetryPolicy::none(), RetryPolicy::default());
+    }
+}
@@ -0,0 +1,190 @@
+//! Test helpers for code that uses [`ValyuClient`](crate::ValyuClient)
+//!
+//! Enabled with the `test-util` feature.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use reqwest::header::HeaderMap;
+
+use crate::transport::{Transport, TransportFuture};
+
+/// In-memory transport that replays canned responses and records requests
+///
+/// Responses are returned in the order they were queued, one per request.
+/// Clones share the same queue and request log, so keep a clone around to
+/// inspect what the client sent.
+///
+/// # Example
+///
+/// ```
+/// use valyu::test_util::{MockResponse, MockTransport};
+/// use valyu::ValyuClient;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = MockTransport::new()
+///     .with_response(MockResponse::json(200, serde_json::json!({
+///         "success": true,
+///         "results": []
+///     })));
+///
+/// let client = ValyuClient::builder()
+///     .api_key("test-key")
+///     .transport(transport.clone())
+///     .build()
+///     .unwrap();
+///
+/// let response = client.search("quantum computing").await.unwrap();
+/// assert!(response.success);
+/// assert!(transport.requests()[0].url.ends_with("/deepsearch"));
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// The transport panics if a request is made when no responses are queued.
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: VecDeque<MockResponse>,
+    requests: Vec<RecordedRequest>,
+}
+
+impl MockTransport {
+    /// Create a transport with no queued responses
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a response to return for a future request
+    pub fn with_response(self, response: MockResponse) -> Self {
+        self.push_response(response);
+        self
+    }
+
+    /// Queue a response without consuming the transport
+    pub fn push_response(&self, response: MockResponse) {
+        self.lock().responses.push_back(response);
+    }
+
+    /// Requests received so far, oldest first
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.lock().requests.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        // A panicking test can poison the lock; the state is still usable
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Transport for MockTransport {
+    fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+        let response = {
+            let mut state = self.lock();
+            state.requests.push(RecordedRequest::from(&request));
+            state.responses.pop_front()
+        };
+
+        let response = response.unwrap_or_else(|| {
+            panic!(
+                "MockTransport has no queued response for {} {}",
+                request.method(),
+                request.url()
+            )
+        });
+
+        Box::pin(async move { Ok(response.into_reqwest()) })
+    }
+}
+
+/// Canned response returned by [`MockTransport`]
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl MockResponse {
+    /// Create a response with a raw body
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Create a JSON response
+    pub fn json(status: u16, body: serde_json::Value) -> Self {
+        Self::new(status, body.to_string()).with_header("content-type", "application/json")
+    }
+
+    /// Add a response header
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    fn into_reqwest(self) -> reqwest::Response {
+        let mut builder = http::Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder
+            .body(self.body)
+            .expect("MockResponse has an invalid status or header");
+        reqwest::Response::from(response)
+    }
+}
+
+/// A request received by [`MockTransport`]
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `"POST"`
+    pub method: String,
+    /// Full request URL
+    pub url: String,
+    /// Request headers
+    pub headers: HeaderMap,
+    /// Request body, if it was sent from memory
+    pub body: Option<Vec<u8>>,
+}
+
+impl RecordedRequest {
+    /// Parse the request body as JSON
+    pub fn json(&self) -> Option<serde_json::Value> {
+        serde_json::from_slice(self.body.as_deref()?).ok()
+    }
+}
+
+impl From<&reqwest::Request> for RecordedRequest {
+    fn from(request: &reqwest::Request) -> Self {
+        Self {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            headers: request.headers().clone(),
+            body: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(<[u8]>::to_vec),
+        }
+    }
+}
+
+/// Build a client that sends every request through `transport`, for the
+/// crate's own tests
+#[cfg(test)]
+pub(crate) fn mock_client(transport: MockTransport) -> crate::ValyuClient {
+    crate::ValyuClient::builder()
+        .api_key("test-key")
+        .transport(transport)
+        .build()
+        .unwrap()
+}
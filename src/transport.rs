@@ -0,0 +1,74 @@
+//! Pluggable HTTP transport used by [`ValyuClient`](crate::ValyuClient)
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::error::{Result, ValyuError};
+
+/// Future returned by [`Transport::execute`]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<reqwest::Response>> + Send + 'a>>;
+
+/// Sends fully-built HTTP requests on behalf of the client
+///
+/// The client builds each request (URL, headers, body, timeout) and hands it
+/// to the transport, then interprets the response itself. Swapping the
+/// transport lets tests run without the network; see `MockTransport` in the
+/// `test_util` module (behind the `test-util` feature).
+///
+/// Transports should return [`ValyuError::Timeout`] for timed-out requests so
+/// that the retry policy can recognise them.
+///
+/// # Example
+///
+/// ```
+/// use valyu::{ReqwestTransport, Transport, TransportFuture, ValyuClient};
+///
+/// #[derive(Debug)]
+/// struct LoggingTransport(ReqwestTransport);
+///
+/// impl Transport for LoggingTransport {
+///     fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+///         println!("{} {}", request.method(), request.url());
+///         self.0.execute(request)
+///     }
+/// }
+///
+/// let client = ValyuClient::builder()
+///     .api_key("your-api-key")
+///     .transport(LoggingTransport(ReqwestTransport::default()))
+///     .build()
+///     .unwrap();
+/// ```
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Send a request and return the raw response
+    fn execute(&self, request: reqwest::Request) -> TransportFuture<'_>;
+}
+
+/// Default transport that sends requests with a [`reqwest::Client`]
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport that sends requests with the given client
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+        Box::pin(async move { self.client.execute(request).await.map_err(map_send_error) })
+    }
+}
+
+/// Convert a transport error, separating timeouts from other failures
+fn map_send_error(error: reqwest::Error) -> ValyuError {
+    if error.is_timeout() {
+        ValyuError::Timeout
+    } else {
+        ValyuError::RequestFailed(error)
+    }
+}
@@ -1,7 +1,315 @@
 //! Type definitions for Valyu API requests and responses
 
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+use base64::Engine;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::auth::redact_api_key;
+use crate::error::{Result, ValyuError};
+
+/// Type of search shared by the DeepSearch, Answer, and DeepResearch APIs
+///
+/// # Example
+///
+/// ```
+/// use valyu::{DeepSearchRequest, SearchType};
+///
+/// let request = DeepSearchRequest::new("quantum computing")
+///     .with_search_type_enum(SearchType::Web);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchType {
+    /// Search both web and proprietary sources
+    All,
+    /// Search the web only
+    Web,
+    /// Search proprietary datasets only
+    Proprietary,
+}
+
+impl SearchType {
+    /// The string value sent to the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::All => "all",
+            SearchType::Web => "web",
+            SearchType::Proprietary => "proprietary",
+        }
+    }
+}
+
+impl fmt::Display for SearchType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SearchType {
+    type Err = ValyuError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "all" => Ok(SearchType::All),
+            "web" => Ok(SearchType::Web),
+            "proprietary" => Ok(SearchType::Proprietary),
+            other => Err(ValyuError::InvalidRequest(format!(
+                "unknown search_type \"{}\" (expected \"all\", \"web\", or \"proprietary\")",
+                other
+            ))),
+        }
+    }
+}
+
+impl From<SearchType> for String {
+    fn from(search_type: SearchType) -> Self {
+        search_type.as_str().to_string()
+    }
+}
+
+/// A source to include in or exclude from a search
+///
+/// Each variant is converted to the string form the API expects:
+///
+/// - [`Domain`](Self::Domain) becomes the bare, lowercase host, so
+///   `"https://www.Nature.com/"` is sent as `"www.nature.com"`
+/// - [`Url`](Self::Url) is sent unchanged, restricting results to that URL
+///   or path prefix, e.g. `"https://arxiv.org/abs/"`
+/// - [`Dataset`](Self::Dataset) is sent unchanged, e.g. `"valyu/valyu-arxiv"`
+///
+/// # Example
+///
+/// ```
+/// use valyu::{DeepSearchRequest, Source};
+///
+/// let request = DeepSearchRequest::new("transformer architectures")
+///     .with_included_source(Source::Domain("arxiv.org".to_string()))
+///     .with_included_source(Source::Dataset("valyu/valyu-pubmed".to_string()))
+///     .with_excluded_source("https://example.com/blog/");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A website domain, e.g. `"arxiv.org"`
+    Domain(String),
+    /// A specific URL or URL prefix, e.g. `"https://arxiv.org/abs/"`
+    Url(String),
+    /// A Valyu dataset identifier, e.g. `"valyu/valyu-arxiv"`
+    Dataset(String),
+}
+
+impl Source {
+    /// The string value sent to the API
+    pub fn to_api_string(&self) -> String {
+        match self {
+            Source::Domain(domain) => {
+                let domain = domain.trim().to_ascii_lowercase();
+                let domain = domain
+                    .strip_prefix("https://")
+                    .or_else(|| domain.strip_prefix("http://"))
+                    .unwrap_or(&domain);
+                domain.split('/').next().unwrap_or(domain).to_string()
+            }
+            Source::Url(url) | Source::Dataset(url) => url.trim().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_api_string())
+    }
+}
+
+/// Guess the kind of source from its string form
+///
+/// Strings starting with `http://` or `https://` are URLs, strings like
+/// `"provider/name"` whose first segment has no dot are datasets, and
+/// anything else is a domain.
+impl From<&str> for Source {
+    fn from(source: &str) -> Self {
+        let source = source.trim();
+        if source.starts_with("http://") || source.starts_with("https://") {
+            return Source::Url(source.to_string());
+        }
+
+        match source.split_once('/') {
+            Some((provider, _)) if !provider.contains('.') => Source::Dataset(source.to_string()),
+            _ => Source::Domain(source.to_string()),
+        }
+    }
+}
+
+impl From<String> for Source {
+    fn from(source: String) -> Self {
+        Source::from(source.as_str())
+    }
+}
+
+impl From<Source> for String {
+    fn from(source: Source) -> Self {
+        source.to_api_string()
+    }
+}
+
+/// Response length for the DeepSearch API
+///
+/// # Example
+///
+/// ```
+/// use valyu::{DeepSearchRequest, DeepSearchResponseLength};
+///
+/// let request = DeepSearchRequest::new("quantum computing")
+///     .with_response_length_enum(DeepSearchResponseLength::Medium);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeepSearchResponseLength {
+    /// Short content per result
+    Short,
+    /// Medium content per result
+    Medium,
+    /// Large content per result
+    Large,
+    /// Full content per result
+    Max,
+}
+
+impl DeepSearchResponseLength {
+    /// The string value sent to the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeepSearchResponseLength::Short => "short",
+            DeepSearchResponseLength::Medium => "medium",
+            DeepSearchResponseLength::Large => "large",
+            DeepSearchResponseLength::Max => "max",
+        }
+    }
+}
+
+impl fmt::Display for DeepSearchResponseLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DeepSearchResponseLength {
+    type Err = ValyuError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "short" => Ok(DeepSearchResponseLength::Short),
+            "medium" => Ok(DeepSearchResponseLength::Medium),
+            "large" => Ok(DeepSearchResponseLength::Large),
+            "max" => Ok(DeepSearchResponseLength::Max),
+            other => Err(ValyuError::InvalidRequest(format!(
+                "unknown response_length \"{}\" (expected \"short\", \"medium\", \"large\", or \"max\")",
+                other
+            ))),
+        }
+    }
+}
+
+impl From<DeepSearchResponseLength> for String {
+    fn from(length: DeepSearchResponseLength) -> Self {
+        length.as_str().to_string()
+    }
+}
+
+/// Check that a country code is two ASCII letters and convert it to uppercase
+fn normalize_country_code(code: &str) -> Result<String> {
+    if code.len() == 2 && code.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(code.to_ascii_uppercase())
+    } else {
+        Err(ValyuError::InvalidRequest(format!(
+            "country_code must be a 2-letter ISO 3166-1 code, got \"{}\"",
+            code
+        )))
+    }
+}
+
+/// Check that a price limit is a positive, finite number of dollars
+fn check_max_price(field: &str, price: f64) -> Result<()> {
+    if price.is_finite() && price > 0.0 {
+        Ok(())
+    } else {
+        Err(ValyuError::InvalidRequest(format!(
+            "{} must be a positive number of dollars, got {}",
+            field, price
+        )))
+    }
+}
+
+/// Check that a relevance threshold is a finite number within 0.0-1.0
+fn check_relevance_threshold(threshold: f64) -> Result<()> {
+    if threshold.is_finite() && (0.0..=1.0).contains(&threshold) {
+        Ok(())
+    } else {
+        Err(ValyuError::InvalidRequest(format!(
+            "relevance_threshold must be between 0.0 and 1.0, got {}",
+            threshold
+        )))
+    }
+}
+
+/// Check that a user-supplied schema is itself a valid JSON Schema document
+#[cfg(feature = "jsonschema")]
+fn check_json_schema(field: &str, schema: &serde_json::Value) -> Result<()> {
+    jsonschema::validator_for(schema)
+        .map(|_| ())
+        .map_err(|error| {
+            let path = error.instance_path.to_string();
+            ValyuError::InvalidRequest(format!(
+                "{} is not a valid JSON Schema at \"{}\": {}",
+                field,
+                if path.is_empty() { "/" } else { &path },
+                error
+            ))
+        })
+}
+
+/// Check that an optional string search type is one the API understands
+fn validate_search_type(search_type: Option<&str>) -> Result<()> {
+    if let Some(search_type) = search_type {
+        search_type.parse::<SearchType>()?;
+    }
+    Ok(())
+}
+
+/// Deserialize an optional count sent either as a number or as a numeric
+/// string (e.g. `42` or `"42"`)
+///
+/// An empty string is treated as missing.
+fn deserialize_number_from_string<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<i32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(i32),
+        String(String),
+    }
+
+    match Option::<NumberOrString>::deserialize(deserializer)? {
+        Some(NumberOrString::Number(number)) => Ok(Some(number)),
+        Some(NumberOrString::String(text)) if text.trim().is_empty() => Ok(None),
+        Some(NumberOrString::String(text)) => {
+            text.trim().parse().map(Some).map_err(|_| {
+                serde::de::Error::custom(format!("expected a number, got \"{}\"", text))
+            })
+        }
+        None => Ok(None),
+    }
+}
+
 /// Request parameters for the Valyu DeepSearch API
 ///
 /// # Example
@@ -14,7 +322,7 @@ use serde::{Deserialize, Serialize};
 ///     .with_search_type("web")
 ///     .with_fast_mode(true);
 /// ```
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeepSearchRequest {
     /// The search query text (required)
     pub query: String,
@@ -59,7 +367,8 @@ pub struct DeepSearchRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country_code: Option<String>,
 
-    /// Whether this is a tool call (default: true)
+    /// Whether results are for an AI agent's tool call rather than a person
+    /// (API default: true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_tool_call: Option<bool>,
 
@@ -70,6 +379,10 @@ pub struct DeepSearchRequest {
     /// End date for filtering results (YYYY-MM-DD)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub end_date: Option<String>,
+
+    /// Timeout for this request only, overriding the client's timeout
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
 }
 
 impl DeepSearchRequest {
@@ -98,9 +411,27 @@ impl DeepSearchRequest {
             is_tool_call: None,
             start_date: None,
             end_date: None,
+            timeout: None,
         }
     }
 
+    /// Create a request whose results are shown to people rather than an AI
+    /// agent
+    ///
+    /// Same as [`new`](Self::new) with [`with_is_tool_call(false)`](Self::with_is_tool_call).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepSearchRequest;
+    ///
+    /// let request = DeepSearchRequest::for_human("best hiking trails near Zurich");
+    /// assert_eq!(request.is_tool_call, Some(false));
+    /// ```
+    pub fn for_human(query: impl Into<String>) -> Self {
+        Self::new(query).with_is_tool_call(false)
+    }
+
     /// Set the maximum number of results (1-20)
     ///
     /// # Example
@@ -117,6 +448,10 @@ impl DeepSearchRequest {
 
     /// Set the search type ("all", "web", or "proprietary")
     ///
+    /// Unknown values are rejected by [`validate`](Self::validate) before the
+    /// request is sent. Prefer [`with_search_type_enum`](Self::with_search_type_enum)
+    /// to catch typos at compile time.
+    ///
     /// # Example
     ///
     /// ```
@@ -129,6 +464,20 @@ impl DeepSearchRequest {
         self
     }
 
+    /// Set the search type from a [`SearchType`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{DeepSearchRequest, SearchType};
+    ///
+    /// let request = DeepSearchRequest::new("AI").with_search_type_enum(SearchType::Web);
+    /// ```
+    pub fn with_search_type_enum(mut self, search_type: SearchType) -> Self {
+        self.search_type = Some(search_type.into());
+        self
+    }
+
     /// Enable or disable fast mode
     ///
     /// Fast mode provides reduced latency but may return shorter results.
@@ -159,6 +508,27 @@ impl DeepSearchRequest {
         self
     }
 
+    /// Set the maximum price per thousand retrievals in dollars, checking that it is a positive, finite amount
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepSearchRequest;
+    ///
+    /// let request = DeepSearchRequest::new("AI").try_with_max_price(10.0)?;
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if the price is zero, negative,
+    /// or not a finite number.
+    pub fn try_with_max_price(mut self, price: f64) -> Result<Self> {
+        check_max_price("max_price", price)?;
+        self.max_price = Some(price);
+        Ok(self)
+    }
+
     /// Set the relevance threshold (0.0-1.0)
     ///
     /// # Example
@@ -173,8 +543,33 @@ impl DeepSearchRequest {
         self
     }
 
+    /// Set the relevance threshold, checking that it is within 0.0-1.0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepSearchRequest;
+    ///
+    /// let request = DeepSearchRequest::new("AI").try_with_relevance_threshold(0.7)?;
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if the threshold is outside
+    /// 0.0-1.0 or not a finite number.
+    pub fn try_with_relevance_threshold(mut self, threshold: f64) -> Result<Self> {
+        check_relevance_threshold(threshold)?;
+        self.relevance_threshold = Some(threshold);
+        Ok(self)
+    }
+
     /// Set the response length ("short", "medium", "large", or "max")
     ///
+    /// Unknown values are rejected by [`validate`](Self::validate) before the
+    /// request is sent. Prefer [`with_response_length_enum`](Self::with_response_length_enum)
+    /// to catch typos at compile time.
+    ///
     /// # Example
     ///
     /// ```
@@ -187,6 +582,21 @@ impl DeepSearchRequest {
         self
     }
 
+    /// Set the response length from a [`DeepSearchResponseLength`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{DeepSearchRequest, DeepSearchResponseLength};
+    ///
+    /// let request = DeepSearchRequest::new("AI")
+    ///     .with_response_length_enum(DeepSearchResponseLength::Large);
+    /// ```
+    pub fn with_response_length_enum(mut self, length: DeepSearchResponseLength) -> Self {
+        self.response_length = Some(length.into());
+        self
+    }
+
     /// Set specific sources to include in the search
     ///
     /// # Example
@@ -217,6 +627,36 @@ impl DeepSearchRequest {
         self
     }
 
+    /// Add one source to include in the search
+    ///
+    /// See [`Source`] for the string each variant is sent as.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{DeepSearchRequest, Source};
+    ///
+    /// let request = DeepSearchRequest::new("AI")
+    ///     .with_included_source(Source::Domain("arxiv.org".to_string()))
+    ///     .with_included_source("valyu/valyu-pubmed");
+    /// ```
+    pub fn with_included_source(mut self, source: impl Into<Source>) -> Self {
+        self.included_sources
+            .get_or_insert_with(Vec::new)
+            .push(source.into().to_api_string());
+        self
+    }
+
+    /// Add one source to exclude from the search
+    ///
+    /// See [`Source`] for the string each variant is sent as.
+    pub fn with_excluded_source(mut self, source: impl Into<Source>) -> Self {
+        self.excluded_sources
+            .get_or_insert_with(Vec::new)
+            .push(source.into().to_api_string());
+        self
+    }
+
     /// Set a natural language category guide phrase
     ///
     /// # Example
@@ -246,8 +686,37 @@ impl DeepSearchRequest {
         self
     }
 
+    /// Set the country code, checking that it is a 2-letter ISO code
+    ///
+    /// The code is converted to uppercase before it is sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepSearchRequest;
+    ///
+    /// let request = DeepSearchRequest::new("AI").try_with_country_code("gb")?;
+    /// assert_eq!(request.country_code.as_deref(), Some("GB"));
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] unless the code is exactly two
+    /// ASCII letters.
+    pub fn try_with_country_code(mut self, code: impl AsRef<str>) -> Result<Self> {
+        self.country_code = Some(normalize_country_code(code.as_ref())?);
+        Ok(self)
+    }
+
     /// Set whether this is a tool call
     ///
+    /// The API assumes requests come from an AI agent and optimizes results
+    /// for tool use unless this is `false`. Search UIs and other apps showing
+    /// results to people should set it to `false`; see also
+    /// [`for_human`](Self::for_human) and
+    /// [`ValyuClientBuilder::default_tool_call`](crate::ValyuClientBuilder::default_tool_call).
+    ///
     /// # Example
     ///
     /// ```
@@ -275,10 +744,76 @@ impl DeepSearchRequest {
         self.end_date = Some(end.into());
         self
     }
+
+    /// Set a timeout for this request, overriding the client's timeout
+    ///
+    /// A request that exceeds it fails with [`ValyuError::Timeout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepSearchRequest;
+    /// use std::time::Duration;
+    ///
+    /// let request = DeepSearchRequest::new("AI")
+    ///     .with_timeout(Duration::from_secs(10));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Check the request for values the API would reject
+    ///
+    /// This is called automatically by [`ValyuClient::deep_search`](crate::ValyuClient::deep_search).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if `search_type` or `response_length`
+    /// is not a known value, `relevance_threshold` is outside 0.0-1.0, or
+    /// `max_price` is not a positive amount.
+    pub fn validate(&self) -> Result<()> {
+        validate_search_type(self.search_type.as_deref())?;
+
+        if let Some(threshold) = self.relevance_threshold {
+            check_relevance_threshold(threshold)?;
+        }
+
+        if let Some(price) = self.max_price {
+            check_max_price("max_price", price)?;
+        }
+
+        if let Some(length) = &self.response_length {
+            length.parse::<DeepSearchResponseLength>()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for DeepSearchRequest {
+    fn from(query: &str) -> Self {
+        Self::new(query)
+    }
+}
+
+impl From<String> for DeepSearchRequest {
+    fn from(query: String) -> Self {
+        Self::new(query)
+    }
+}
+
+/// Lets [`ValyuClient::deep_search`](crate::ValyuClient::deep_search) accept
+/// a borrowed request
+impl From<&DeepSearchRequest> for DeepSearchRequest {
+    fn from(request: &DeepSearchRequest) -> Self {
+        request.clone()
+    }
 }
 
 /// Response from the Valyu DeepSearch API
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepSearchResponse {
     /// Whether the request was successful
     pub success: bool,
@@ -293,6 +828,10 @@ pub struct DeepSearchResponse {
     pub query: Option<String>,
 
     /// Array of search results
+    ///
+    /// Responses returned by [`ValyuClient::deep_search`](crate::ValyuClient::deep_search)
+    /// set this to an empty list when a successful search found nothing, so
+    /// `None` only occurs in responses deserialized by hand.
     pub results: Option<Vec<SearchResult>>,
 
     /// Breakdown of results by source type
@@ -305,37 +844,175 @@ pub struct DeepSearchResponse {
     pub total_deduction_dollars: Option<f64>,
 
     /// Total number of characters in results
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub total_characters: Option<i32>,
-}
-
-/// Individual search result from the Valyu API
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct SearchResult {
-    /// Unique identifier for the result
-    pub id: Option<String>,
 
-    /// Title of the result
-    pub title: Option<String>,
+    /// HTTP status the response was received with, set by the client
+    ///
+    /// `206` means the response is partial, e.g. because some sources timed
+    /// out; see [`is_partial`](Self::is_partial).
+    #[serde(skip)]
+    pub http_status: Option<u16>,
 
-    /// URL of the source
-    pub url: Option<String>,
+    /// Rate-limit headers the response was received with, set by the client
+    ///
+    /// `None` when the API sent none of them.
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimitInfo>,
 
-    /// Content snippet or full text
-    pub content: Option<String>,
+    /// Fields returned by the API that this SDK doesn't model yet
+    ///
+    /// Always empty with the `strict-parsing` feature, which rejects unknown
+    /// fields instead.
+    #[cfg_attr(not(feature = "strict-parsing"), serde(flatten))]
+    #[cfg_attr(feature = "strict-parsing", serde(skip))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
 
-    /// Description/summary of the result
-    pub description: Option<String>,
+impl DeepSearchResponse {
+    /// Number of results returned (0 if there are none)
+    pub fn result_count(&self) -> usize {
+        self.results.as_ref().map_or(0, Vec::len)
+    }
 
-    /// Source type (e.g., "web", "proprietary")
-    pub source: Option<String>,
+    /// Whether the API returned partial results (HTTP 206 Partial Content)
+    ///
+    /// Some sources may have timed out or failed; retry or warn if complete
+    /// data matters.
+    pub fn is_partial(&self) -> bool {
+        self.http_status == Some(206)
+    }
 
-    /// Source type detail (e.g., "website")
-    pub source_type: Option<String>,
+    /// Iterate over the results, yielding nothing if there are none
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepSearchResponse;
+    ///
+    /// # fn print(response: &DeepSearchResponse) {
+    /// for result in response.results_iter() {
+    ///     println!("{}", result.title.as_deref().unwrap_or("Untitled"));
+    /// }
+    /// # }
+    /// ```
+    pub fn results_iter(&self) -> impl Iterator<Item = &SearchResult> {
+        self.results.iter().flatten()
+    }
 
-    /// Data type ("structured" or "unstructured")
-    pub data_type: Option<String>,
+    /// Take ownership of the results, returning an empty vector if there are none
+    pub fn into_results(self) -> Vec<SearchResult> {
+        self.results.unwrap_or_default()
+    }
+
+    /// Results with a relevance score of at least `min_score`
+    ///
+    /// Results without a relevance score are excluded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepSearchResponse;
+    ///
+    /// # fn print(response: &DeepSearchResponse) {
+    /// for result in response.results_above(0.8) {
+    ///     println!("{}", result.title.as_deref().unwrap_or("Untitled"));
+    /// }
+    /// # }
+    /// ```
+    pub fn results_above(&self, min_score: f64) -> Vec<&SearchResult> {
+        self.results_iter()
+            .filter(|result| {
+                result
+                    .relevance_score
+                    .is_some_and(|score| score >= min_score)
+            })
+            .collect()
+    }
+
+    /// Build a Contents request for the first `max` unique result URLs
+    ///
+    /// Results are taken in order, skipping those without a URL and URLs
+    /// already taken, until `max` URLs are collected; the count is capped at
+    /// 10 (the Contents API limit) whatever `max` is. Sort with
+    /// [`sort_by_relevance`](Self::sort_by_relevance) first to extract the
+    /// best matches.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = ValyuClient::new("your-api-key");
+    ///     let search = client.search("quantum error correction").await?;
+    ///
+    ///     let request = search.to_contents_request(3).with_response_length("medium");
+    ///     let contents = client.contents(&request).await?;
+    ///     println!("Extracted {} pages", contents.results.map_or(0, |r| r.len()));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_contents_request(&self, max: usize) -> ContentsRequest {
+        let mut seen = HashSet::new();
+        let urls = self
+            .results_iter()
+            .filter_map(|result| result.url.as_deref())
+            .filter(|url| !url.trim().is_empty() && seen.insert(*url))
+            .take(max.min(MAX_CONTENTS_URLS))
+            .map(str::to_string)
+            .collect();
+
+        ContentsRequest::new(urls)
+    }
+
+    /// Sort results by relevance score, highest first
+    ///
+    /// Results without a relevance score are moved to the end, keeping their
+    /// original order.
+    pub fn sort_by_relevance(&mut self) {
+        if let Some(results) = &mut self.results {
+            results.sort_by(|a, b| match (a.relevance_score, b.relevance_score) {
+                (Some(a), Some(b)) => b.total_cmp(&a),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            });
+        }
+    }
+}
+
+/// Individual search result from the Valyu API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct SearchResult {
+    /// Unique identifier for the result
+    pub id: Option<String>,
+
+    /// Title of the result
+    pub title: Option<String>,
+
+    /// URL of the source
+    pub url: Option<String>,
+
+    /// Content snippet or full text
+    pub content: Option<String>,
+
+    /// Description/summary of the result
+    pub description: Option<String>,
+
+    /// Source type (e.g., "web", "proprietary")
+    pub source: Option<String>,
+
+    /// Source type detail (e.g., "website")
+    pub source_type: Option<String>,
+
+    /// Data type ("structured" or "unstructured")
+    pub data_type: Option<String>,
 
     /// Length of the content in characters
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub length: Option<i32>,
 
     /// Price/cost of this result
@@ -354,6 +1031,7 @@ pub struct SearchResult {
     pub citation: Option<String>,
 
     /// Number of times this source has been cited
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub citation_count: Option<i32>,
 
     /// List of authors
@@ -363,8 +1041,178 @@ pub struct SearchResult {
     pub relevance_score: Option<f64>,
 }
 
+impl SearchResult {
+    /// Get the associated image URLs as an ordered list
+    ///
+    /// `image_url` may be an object with numeric keys (`{"0": "...", "1": "..."}`),
+    /// a map with arbitrary keys, a single string, or an array. Numeric keys are
+    /// returned in numeric order, followed by any other keys in map order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::SearchResult;
+    ///
+    /// # fn print(result: &SearchResult) {
+    /// for url in result.image_urls() {
+    ///     println!("{}", url);
+    /// }
+    /// # }
+    /// ```
+    pub fn image_urls(&self) -> Vec<String> {
+        match &self.image_url {
+            Some(serde_json::Value::Object(map)) => {
+                let mut numbered: Vec<(u64, &serde_json::Value)> = Vec::new();
+                let mut named: Vec<&serde_json::Value> = Vec::new();
+                for (key, value) in map {
+                    match key.parse::<u64>() {
+                        Ok(index) => numbered.push((index, value)),
+                        Err(_) => named.push(value),
+                    }
+                }
+                numbered.sort_by_key(|(index, _)| *index);
+
+                numbered
+                    .into_iter()
+                    .map(|(_, value)| value)
+                    .chain(named)
+                    .filter_map(|value| value.as_str().map(str::to_string))
+                    .collect()
+            }
+            Some(serde_json::Value::Array(values)) => values
+                .iter()
+                .filter_map(|value| value.as_str().map(str::to_string))
+                .collect(),
+            Some(serde_json::Value::String(url)) => vec![url.clone()],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Format the result as an APA-style reference
+    ///
+    /// Returns the API's `citation` verbatim when it is provided. Otherwise
+    /// the reference is assembled from the authors, publication year, title,
+    /// and DOI (or URL if there is no DOI), leaving out whatever is missing;
+    /// a result without a title has no citation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::SearchResult;
+    ///
+    /// let result: SearchResult = serde_json::from_value(serde_json::json!({
+    ///     "title": "Attention Is All You Need",
+    ///     "authors": ["Vaswani, A.", "Shazeer, N.", "Parmar, N."],
+    ///     "publication_date": "2017-06-12",
+    ///     "doi": "10.48550/arXiv.1706.03762"
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     result.to_citation().as_deref(),
+    ///     Some("Vaswani, A., Shazeer, N., & Parmar, N. (2017). Attention Is All You Need. https://doi.org/10.48550/arXiv.1706.03762")
+    /// );
+    /// ```
+    pub fn to_citation(&self) -> Option<String> {
+        if let Some(citation) = self.citation.as_deref().map(str::trim) {
+            if !citation.is_empty() {
+                return Some(citation.to_string());
+            }
+        }
+
+        let title = self
+            .title
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())?;
+        let title = title.trim_end_matches('.');
+
+        let year = self
+            .publication_date
+            .as_deref()
+            .and_then(|date| date.get(..4))
+            .filter(|year| year.chars().all(|c| c.is_ascii_digit()))
+            .unwrap_or("n.d.");
+
+        let authors: Vec<&str> = self
+            .authors
+            .iter()
+            .flatten()
+            .map(|author| author.trim())
+            .filter(|author| !author.is_empty())
+            .collect();
+        let authors = match authors.as_slice() {
+            [] => None,
+            [only] => Some(only.to_string()),
+            [first, second] => Some(format!("{} & {}", first, second)),
+            [rest @ .., last] => Some(format!("{}, & {}", rest.join(", "), last)),
+        };
+
+        let mut citation = match authors {
+            Some(authors) => format!("{} ({}). {}.", authors, year, title),
+            None => format!("{}. ({}).", title, year),
+        };
+
+        match (self.doi_url(), self.url.as_deref()) {
+            (Some(doi_url), _) => citation.push_str(&format!(" {}", doi_url)),
+            (None, Some(url)) => citation.push_str(&format!(" {}", url)),
+            (None, None) => {}
+        }
+
+        Some(citation)
+    }
+
+    /// Whether the result is an academic paper
+    ///
+    /// True when the result has a DOI or its `source_type` marks it as a
+    /// paper (e.g. "paper", "research_paper", "academic").
+    pub fn is_academic(&self) -> bool {
+        if self.doi_url().is_some() {
+            return true;
+        }
+
+        self.source_type.as_deref().is_some_and(|source_type| {
+            let source_type = source_type.trim().to_ascii_lowercase();
+            source_type.contains("paper") || source_type.contains("academic")
+        })
+    }
+
+    /// Get the DOI as a resolvable `https://doi.org/` link
+    ///
+    /// Accepts DOIs given bare (`10.1000/xyz`), with a `doi:` prefix, or
+    /// already as a doi.org URL. Returns `None` when there is no DOI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::SearchResult;
+    ///
+    /// let result: SearchResult =
+    ///     serde_json::from_value(serde_json::json!({ "doi": "doi:10.1000/xyz" })).unwrap();
+    ///
+    /// assert!(result.is_academic());
+    /// assert_eq!(result.doi_url().as_deref(), Some("https://doi.org/10.1000/xyz"));
+    /// ```
+    pub fn doi_url(&self) -> Option<String> {
+        let doi = self.doi.as_deref()?.trim();
+        let doi = doi
+            .strip_prefix("https://doi.org/")
+            .or_else(|| doi.strip_prefix("http://doi.org/"))
+            .or_else(|| doi.strip_prefix("doi:"))
+            .unwrap_or(doi)
+            .trim();
+
+        if doi.is_empty() {
+            None
+        } else {
+            Some(format!("https://doi.org/{}", doi))
+        }
+    }
+}
+
 /// Breakdown of results by source type
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct ResultsBySource {
     /// Number of web results
     pub web: Option<i32>,
@@ -373,8 +1221,112 @@ pub struct ResultsBySource {
     pub proprietary: Option<i32>,
 }
 
+impl ResultsBySource {
+    /// Total number of results across all sources, counting missing ones as 0
+    pub fn total(&self) -> i32 {
+        self.web.unwrap_or(0) + self.proprietary.unwrap_or(0)
+    }
+}
+
+/// Outcome of [`ValyuClient::search_until_budget`](crate::ValyuClient::search_until_budget)
+///
+/// Holds everything received before the run stopped, so spend is still
+/// accounted for when a search fails partway through.
+#[derive(Debug, Default)]
+pub struct BudgetedSearchResult {
+    /// Responses received, in request order
+    pub responses: Vec<DeepSearchResponse>,
+
+    /// Total of `total_deduction_dollars` across `responses`
+    pub spent_usd: f64,
+
+    /// The error that stopped the run early, if any
+    pub error: Option<ValyuError>,
+}
+
 // ========== Contents API Types ==========
 
+/// Maximum number of URLs accepted by a single Contents request
+const MAX_CONTENTS_URLS: usize = 10;
+
+/// Smallest custom response length, in characters, accepted by the Contents API
+const MIN_CUSTOM_RESPONSE_LENGTH: i32 = 1_000;
+
+/// Largest custom response length, in characters, accepted by the Contents API
+const MAX_CUSTOM_RESPONSE_LENGTH: i32 = 1_000_000;
+
+/// Check that a custom response length is within the range the API accepts
+fn check_custom_response_length(chars: i32) -> Result<()> {
+    if (MIN_CUSTOM_RESPONSE_LENGTH..=MAX_CUSTOM_RESPONSE_LENGTH).contains(&chars) {
+        Ok(())
+    } else {
+        Err(ValyuError::InvalidRequest(format!(
+            "custom response_length must be between {} and {} characters, got {}",
+            MIN_CUSTOM_RESPONSE_LENGTH, MAX_CUSTOM_RESPONSE_LENGTH, chars
+        )))
+    }
+}
+
+/// Extraction effort for the Contents API
+///
+/// # Example
+///
+/// ```
+/// use valyu::{ContentsRequest, ExtractEffort};
+///
+/// let request = ContentsRequest::new(vec!["https://example.com".to_string()])
+///     .with_extract_effort_enum(ExtractEffort::High);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExtractEffort {
+    /// Fastest extraction
+    Normal,
+    /// Better quality, slower extraction
+    High,
+    /// Let the API choose
+    Auto,
+}
+
+impl ExtractEffort {
+    /// The string value sent to the API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExtractEffort::Normal => "normal",
+            ExtractEffort::High => "high",
+            ExtractEffort::Auto => "auto",
+        }
+    }
+}
+
+impl fmt::Display for ExtractEffort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExtractEffort {
+    type Err = ValyuError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "normal" => Ok(ExtractEffort::Normal),
+            "high" => Ok(ExtractEffort::High),
+            "auto" => Ok(ExtractEffort::Auto),
+            other => Err(ValyuError::InvalidRequest(format!(
+                "unknown extract_effort \"{}\" (expected \"normal\", \"high\", or \"auto\")",
+                other
+            ))),
+        }
+    }
+}
+
+impl From<ExtractEffort> for String {
+    fn from(effort: ExtractEffort) -> Self {
+        effort.as_str().to_string()
+    }
+}
+
 /// Request parameters for the Valyu Contents API
 ///
 /// Extract and process content from up to 10 URLs.
@@ -391,7 +1343,7 @@ pub struct ResultsBySource {
 /// .with_response_length("medium")
 /// .with_extract_effort("high");
 /// ```
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContentsRequest {
     /// Array of 1-10 URLs to process (must use http/https protocol)
     pub urls: Vec<String>,
@@ -411,6 +1363,10 @@ pub struct ContentsRequest {
     /// Maximum cost in dollars (defaults to 2x estimated cost)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_price_dollars: Option<f64>,
+
+    /// Timeout for this request only, overriding the client's timeout
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
 }
 
 /// Response length configuration for Contents API
@@ -454,9 +1410,51 @@ impl ContentsRequest {
             extract_effort: None,
             summary: None,
             max_price_dollars: None,
+            timeout: None,
         }
     }
 
+    /// Add a URL to extract
+    ///
+    /// The 10-URL limit is checked by [`validate`](Self::validate) when the
+    /// request is sent, so URLs can be added freely while building it up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::ContentsRequest;
+    ///
+    /// let mut request = ContentsRequest::new(Vec::new());
+    /// for url in ["https://example.com/a", "https://example.com/b"] {
+    ///     request = request.add_url(url);
+    /// }
+    /// ```
+    pub fn add_url(mut self, url: impl Into<String>) -> Self {
+        self.urls.push(url.into());
+        self
+    }
+
+    /// Add several URLs to extract, e.g. from earlier search results
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{ContentsRequest, DeepSearchResponse};
+    ///
+    /// # fn build(response: &DeepSearchResponse) -> ContentsRequest {
+    /// ContentsRequest::new(Vec::new())
+    ///     .add_urls(response.results_iter().filter_map(|result| result.url.clone()))
+    /// # }
+    /// ```
+    pub fn add_urls<I>(mut self, urls: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.urls.extend(urls.into_iter().map(Into::into));
+        self
+    }
+
     /// Set the response length
     ///
     /// # Example
@@ -487,6 +1485,29 @@ impl ContentsRequest {
         self
     }
 
+    /// Set a custom response length, checking that it is within
+    /// 1,000-1,000,000 characters
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::ContentsRequest;
+    ///
+    /// let request = ContentsRequest::new(vec!["https://example.com".to_string()])
+    ///     .try_with_custom_response_length(30000)?;
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if `chars` is outside
+    /// 1,000-1,000,000, including zero and negative values.
+    pub fn try_with_custom_response_length(mut self, chars: i32) -> Result<Self> {
+        check_custom_response_length(chars)?;
+        self.response_length = Some(ResponseLength::Custom(chars));
+        Ok(self)
+    }
+
     /// Set the extraction effort level
     ///
     /// # Example
@@ -502,6 +1523,21 @@ impl ContentsRequest {
         self
     }
 
+    /// Set the extraction effort from an [`ExtractEffort`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{ContentsRequest, ExtractEffort};
+    ///
+    /// let request = ContentsRequest::new(vec!["https://example.com".to_string()])
+    ///     .with_extract_effort_enum(ExtractEffort::Auto);
+    /// ```
+    pub fn with_extract_effort_enum(mut self, effort: ExtractEffort) -> Self {
+        self.extract_effort = Some(effort.into());
+        self
+    }
+
     /// Enable or disable default summarization
     ///
     /// # Example
@@ -556,6 +1592,32 @@ impl ContentsRequest {
         self
     }
 
+    /// Set the structured extraction schema from a Rust type
+    ///
+    /// The schema is generated with [`schemars`], so it stays in sync with
+    /// the type; read the extracted data back with
+    /// [`ContentResult::content_as`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Deserialize;
+    /// use valyu::ContentsRequest;
+    ///
+    /// #[derive(Deserialize, schemars::JsonSchema)]
+    /// struct Article {
+    ///     title: String,
+    ///     summary: String,
+    /// }
+    ///
+    /// let request = ContentsRequest::new(vec!["https://example.com".to_string()])
+    ///     .with_summary_schema_for::<Article>();
+    /// ```
+    #[cfg(feature = "schemars")]
+    pub fn with_summary_schema_for<T: schemars::JsonSchema>(self) -> Self {
+        self.with_summary_schema(schemars::schema_for!(T).to_value())
+    }
+
     /// Set the maximum price in dollars
     ///
     /// # Example
@@ -570,10 +1632,111 @@ impl ContentsRequest {
         self.max_price_dollars = Some(max_price);
         self
     }
+
+    /// Set the maximum price in dollars, checking that it is a positive, finite amount
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::ContentsRequest;
+    ///
+    /// let request = ContentsRequest::new(vec!["https://example.com".to_string()])
+    ///     .try_with_max_price_dollars(5.0)?;
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if the price is zero, negative,
+    /// or not a finite number.
+    pub fn try_with_max_price_dollars(mut self, max_price: f64) -> Result<Self> {
+        check_max_price("max_price_dollars", max_price)?;
+        self.max_price_dollars = Some(max_price);
+        Ok(self)
+    }
+
+    /// Set a timeout for this request, overriding the client's timeout
+    ///
+    /// A request that exceeds it fails with [`ValyuError::Timeout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::ContentsRequest;
+    /// use std::time::Duration;
+    ///
+    /// let request = ContentsRequest::new(vec!["https://example.com".to_string()])
+    ///     .with_timeout(Duration::from_secs(10));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Check the request for values the API would reject
+    ///
+    /// This is called automatically by [`ValyuClient::contents`](crate::ValyuClient::contents).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if:
+    /// - No URLs or more than 10 URLs are provided
+    /// - Any URL does not use the `http://` or `https://` protocol
+    /// - A custom `response_length` is outside 1,000-1,000,000 characters
+    /// - `extract_effort` is not a known value
+    /// - `max_price_dollars` is not a positive amount
+    /// - A summary schema is not a valid JSON Schema (with the `jsonschema`
+    ///   feature)
+    pub fn validate(&self) -> Result<()> {
+        if self.urls.is_empty() {
+            return Err(ValyuError::InvalidRequest(
+                "at least one URL is required".to_string(),
+            ));
+        }
+
+        if self.urls.len() > MAX_CONTENTS_URLS {
+            return Err(ValyuError::InvalidRequest(format!(
+                "at most {} URLs can be processed per request, got {}",
+                MAX_CONTENTS_URLS,
+                self.urls.len()
+            )));
+        }
+
+        if let Some(url) = self
+            .urls
+            .iter()
+            .find(|url| !url.starts_with("http://") && !url.starts_with("https://"))
+        {
+            return Err(ValyuError::InvalidRequest(format!(
+                "URL must use the http:// or https:// protocol: {}",
+                url
+            )));
+        }
+
+        if let Some(ResponseLength::Custom(chars)) = self.response_length {
+            check_custom_response_length(chars)?;
+        }
+
+        if let Some(effort) = &self.extract_effort {
+            effort.parse::<ExtractEffort>()?;
+        }
+
+        #[cfg(feature = "jsonschema")]
+        if let Some(SummaryOption::Schema(schema)) = &self.summary {
+            check_json_schema("summary", schema)?;
+        }
+
+        if let Some(price) = self.max_price_dollars {
+            check_max_price("max_price_dollars", price)?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Response from the Valyu Contents API
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct ContentsResponse {
     /// Whether the request was successful
     pub success: bool,
@@ -588,23 +1751,86 @@ pub struct ContentsResponse {
     pub results: Option<Vec<ContentResult>>,
 
     /// Number of URLs requested
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub urls_requested: Option<i32>,
 
     /// Number of URLs successfully processed
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub urls_processed: Option<i32>,
 
     /// Number of URLs that failed processing
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub urls_failed: Option<i32>,
 
     /// Total cost in dollars
     pub total_cost_dollars: Option<f64>,
 
     /// Total number of characters in results
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub total_characters: Option<i32>,
+
+    /// HTTP status the response was received with, set by the client
+    ///
+    /// `206` means only some of the URLs could be processed; see
+    /// [`is_partial`](Self::is_partial).
+    #[serde(skip)]
+    pub http_status: Option<u16>,
+
+    /// Rate-limit headers the response was received with, set by the client
+    ///
+    /// `None` when the API sent none of them.
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimitInfo>,
+}
+
+impl ContentsResponse {
+    /// Whether the API returned partial results (HTTP 206 Partial Content)
+    ///
+    /// Some of the requested URLs could not be fetched or extracted; compare
+    /// `urls_processed` with `urls_requested`, or check
+    /// [`failed_urls`](Self::failed_urls), to see which are missing.
+    pub fn is_partial(&self) -> bool {
+        self.http_status == Some(206)
+    }
+
+    /// Map each processed URL to its result
+    ///
+    /// Results without a URL are left out.
+    pub fn results_by_url(&self) -> HashMap<String, &ContentResult> {
+        self.results
+            .iter()
+            .flatten()
+            .filter_map(|result| Some((result.url.clone()?, result)))
+            .collect()
+    }
+
+    /// The requested URLs that have no result, in request order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{ContentsRequest, ContentsResponse};
+    ///
+    /// # fn retry(request: &ContentsRequest, response: &ContentsResponse) {
+    /// let failed = response.failed_urls(&request.urls);
+    /// if !failed.is_empty() {
+    ///     let retry = ContentsRequest::new(failed);
+    /// }
+    /// # }
+    /// ```
+    pub fn failed_urls(&self, requested: &[String]) -> Vec<String> {
+        let processed = self.results_by_url();
+        requested
+            .iter()
+            .filter(|url| !processed.contains_key(url.as_str()))
+            .cloned()
+            .collect()
+    }
 }
 
 /// Individual content result from the Contents API
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct ContentResult {
     /// Title of the content
     pub title: Option<String>,
@@ -628,16 +1854,95 @@ pub struct ContentResult {
     pub cost_dollars: Option<f64>,
 
     /// Number of characters
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
     pub characters: Option<i32>,
-}
 
-// ========== Answer API Types ==========
+    /// Fields returned by the API that this SDK doesn't model yet
+    ///
+    /// Always empty with the `strict-parsing` feature, which rejects unknown
+    /// fields instead.
+    #[cfg_attr(not(feature = "strict-parsing"), serde(flatten))]
+    #[cfg_attr(feature = "strict-parsing", serde(skip))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
 
-/// Request parameters for the Valyu Answer API
-///
-/// Get AI-powered answers with automatic source retrieval.
-///
-/// # Example
+impl ContentResult {
+    /// Get the extracted content as a string
+    ///
+    /// Markdown content is returned as-is; structured content (from a summary
+    /// schema) is pretty-printed as JSON. Returns `None` if there is no content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::ContentResult;
+    ///
+    /// # fn print(result: &ContentResult) {
+    /// if let Some(text) = result.content_as_string() {
+    ///     println!("{}", text);
+    /// }
+    /// # }
+    /// ```
+    pub fn content_as_string(&self) -> Option<String> {
+        match self.content.as_ref()? {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(text) => Some(text.clone()),
+            other => {
+                Some(serde_json::to_string_pretty(other).unwrap_or_else(|_| other.to_string()))
+            }
+        }
+    }
+
+    /// Deserialize structured content into a custom type
+    ///
+    /// Use this when the request set a summary schema with
+    /// [`ContentsRequest::with_summary_schema`] (or `with_summary_schema_for`
+    /// with the `schemars` feature).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Deserialize;
+    /// use valyu::ContentResult;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Article {
+    ///     title: String,
+    ///     summary: String,
+    /// }
+    ///
+    /// # fn parse(result: &ContentResult) -> valyu::Result<()> {
+    /// let article: Article = result.content_as()?;
+    /// println!("{}: {}", article.title, article.summary);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::ParseError`] if there is no content or it does not
+    /// match `T`.
+    pub fn content_as<T: DeserializeOwned>(&self) -> Result<T> {
+        let content = self
+            .content
+            .as_ref()
+            .ok_or_else(|| ValyuError::ParseError("content result has no content".to_string()))?;
+
+        T::deserialize(content)
+            .map_err(|e| ValyuError::ParseError(format!("Failed to parse content: {}", e)))
+    }
+}
+
+// ========== Answer API Types ==========
+
+/// Maximum length of Answer API system instructions, in characters
+const MAX_SYSTEM_INSTRUCTIONS_CHARS: usize = 2000;
+
+/// Request parameters for the Valyu Answer API
+///
+/// Get AI-powered answers with automatic source retrieval.
+///
+/// # Example
 ///
 /// ```
 /// use valyu::AnswerRequest;
@@ -646,7 +1951,7 @@ pub struct ContentResult {
 ///     .with_search_type("web")
 ///     .with_system_instructions("Focus on breakthroughs from 2024");
 /// ```
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnswerRequest {
     /// The search query to process (required)
     pub query: String,
@@ -690,6 +1995,10 @@ pub struct AnswerRequest {
     /// 2-letter ISO country code
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country_code: Option<String>,
+
+    /// Timeout for this request only, overriding the client's timeout
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
 }
 
 impl AnswerRequest {
@@ -715,6 +2024,7 @@ impl AnswerRequest {
             start_date: None,
             end_date: None,
             country_code: None,
+            timeout: None,
         }
     }
 
@@ -759,6 +2069,9 @@ impl AnswerRequest {
 
     /// Set the search type
     ///
+    /// Unknown values are rejected by [`validate`](Self::validate) before the
+    /// request is sent.
+    ///
     /// # Example
     ///
     /// ```
@@ -772,6 +2085,21 @@ impl AnswerRequest {
         self
     }
 
+    /// Set the search type from a [`SearchType`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{AnswerRequest, SearchType};
+    ///
+    /// let request = AnswerRequest::new("quantum computing")
+    ///     .with_search_type_enum(SearchType::Proprietary);
+    /// ```
+    pub fn with_search_type_enum(mut self, search_type: SearchType) -> Self {
+        self.search_type = Some(search_type.into());
+        self
+    }
+
     /// Enable fast mode
     ///
     /// # Example
@@ -802,6 +2130,27 @@ impl AnswerRequest {
         self
     }
 
+    /// Set the maximum data price, checking that it is a positive, finite amount
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::AnswerRequest;
+    ///
+    /// let request = AnswerRequest::new("quantum computing").try_with_data_max_price(20.0)?;
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if the price is zero, negative,
+    /// or not a finite number.
+    pub fn try_with_data_max_price(mut self, price: f64) -> Result<Self> {
+        check_max_price("data_max_price", price)?;
+        self.data_max_price = Some(price);
+        Ok(self)
+    }
+
     /// Set included sources
     ///
     /// # Example
@@ -832,6 +2181,36 @@ impl AnswerRequest {
         self
     }
 
+    /// Add one source to include in the search
+    ///
+    /// See [`Source`] for the string each variant is sent as.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{AnswerRequest, Source};
+    ///
+    /// let request = AnswerRequest::new("quantum computing")
+    ///     .with_included_source(Source::Domain("arxiv.org".to_string()))
+    ///     .with_included_source("valyu/valyu-pubmed");
+    /// ```
+    pub fn with_included_source(mut self, source: impl Into<Source>) -> Self {
+        self.included_sources
+            .get_or_insert_with(Vec::new)
+            .push(source.into().to_api_string());
+        self
+    }
+
+    /// Add one source to exclude from the search
+    ///
+    /// See [`Source`] for the string each variant is sent as.
+    pub fn with_excluded_source(mut self, source: impl Into<Source>) -> Self {
+        self.excluded_sources
+            .get_or_insert_with(Vec::new)
+            .push(source.into().to_api_string());
+        self
+    }
+
     /// Set date range for filtering
     ///
     /// # Example
@@ -862,10 +2241,101 @@ impl AnswerRequest {
         self.country_code = Some(code.into());
         self
     }
+
+    /// Set the country code, checking that it is a 2-letter ISO code
+    ///
+    /// The code is converted to uppercase before it is sent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::AnswerRequest;
+    ///
+    /// let request = AnswerRequest::new("AI").try_with_country_code("gb")?;
+    /// assert_eq!(request.country_code.as_deref(), Some("GB"));
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] unless the code is exactly two
+    /// ASCII letters.
+    pub fn try_with_country_code(mut self, code: impl AsRef<str>) -> Result<Self> {
+        self.country_code = Some(normalize_country_code(code.as_ref())?);
+        Ok(self)
+    }
+
+    /// Set a timeout for this request, overriding the client's timeout
+    ///
+    /// A request that exceeds it fails with [`ValyuError::Timeout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::AnswerRequest;
+    /// use std::time::Duration;
+    ///
+    /// let request = AnswerRequest::new("quantum computing")
+    ///     .with_timeout(Duration::from_secs(10));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Check the request for values the API would reject
+    ///
+    /// This is called automatically by [`ValyuClient::answer`](crate::ValyuClient::answer).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if:
+    /// - `search_type` is not a known value
+    /// - `system_instructions` is longer than 2000 characters
+    /// - `data_max_price` is not a positive amount
+    /// - `structured_output` is not a valid JSON Schema (with the `jsonschema`
+    ///   feature)
+    pub fn validate(&self) -> Result<()> {
+        validate_search_type(self.search_type.as_deref())?;
+
+        if let Some(price) = self.data_max_price {
+            check_max_price("data_max_price", price)?;
+        }
+
+        #[cfg(feature = "jsonschema")]
+        if let Some(schema) = &self.structured_output {
+            check_json_schema("structured_output", schema)?;
+        }
+
+        if let Some(instructions) = &self.system_instructions {
+            let length = instructions.chars().count();
+            if length > MAX_SYSTEM_INSTRUCTIONS_CHARS {
+                return Err(ValyuError::InvalidRequest(format!(
+                    "system_instructions must be at most {} characters, got {}",
+                    MAX_SYSTEM_INSTRUCTIONS_CHARS, length
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for AnswerRequest {
+    fn from(query: &str) -> Self {
+        Self::new(query)
+    }
+}
+
+impl From<String> for AnswerRequest {
+    fn from(query: String) -> Self {
+        Self::new(query)
+    }
 }
 
 /// Response from the Valyu Answer API
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct AnswerResponse {
     /// Whether the request was successful
     pub success: bool,
@@ -896,10 +2366,98 @@ pub struct AnswerResponse {
 
     /// Cost breakdown
     pub cost: Option<AnswerCost>,
+
+    /// Rate-limit headers the response was received with, set by the client
+    ///
+    /// `None` when the API sent none of them.
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimitInfo>,
+
+    /// Fields returned by the API that this SDK doesn't model yet
+    ///
+    /// Always empty with the `strict-parsing` feature, which rejects unknown
+    /// fields instead.
+    #[cfg_attr(not(feature = "strict-parsing"), serde(flatten))]
+    #[cfg_attr(feature = "strict-parsing", serde(skip))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl AnswerResponse {
+    /// Whether the API returned structured output (`data_type` is `"structured"`)
+    ///
+    /// A request with a schema can still get an unstructured answer, e.g.
+    /// when the model declines to answer.
+    pub fn is_structured(&self) -> bool {
+        self.data_type.as_deref() == Some("structured")
+    }
+
+    /// Get the answer text for an unstructured response
+    ///
+    /// Returns `None` if there are no contents or they are structured JSON.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::AnswerResponse;
+    ///
+    /// # fn print(response: &AnswerResponse) {
+    /// if let Some(answer) = response.answer_text() {
+    ///     println!("{}", answer);
+    /// }
+    /// # }
+    /// ```
+    pub fn answer_text(&self) -> Option<&str> {
+        self.contents.as_ref()?.as_str()
+    }
+
+    /// Deserialize structured contents into a custom type
+    ///
+    /// Use this when the request set a schema with
+    /// [`AnswerRequest::with_structured_output`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Deserialize;
+    /// use valyu::AnswerResponse;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Summary {
+    ///     summary: String,
+    ///     key_points: Vec<String>,
+    /// }
+    ///
+    /// # fn parse(response: &AnswerResponse) -> valyu::Result<()> {
+    /// let answer: Summary = response.parse_contents()?;
+    /// println!("{}", answer.summary);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::ParseError`] if the API returned an unstructured
+    /// answer, there are no contents, or they do not match `T`.
+    pub fn parse_contents<T: DeserializeOwned>(&self) -> Result<T> {
+        if self.data_type.as_deref() == Some("unstructured") {
+            return Err(ValyuError::ParseError(
+                "answer is unstructured text, not structured output".to_string(),
+            ));
+        }
+
+        let contents = self
+            .contents
+            .as_ref()
+            .ok_or_else(|| ValyuError::ParseError("answer has no contents".to_string()))?;
+
+        T::deserialize(contents)
+            .map_err(|e| ValyuError::ParseError(format!("Failed to parse answer contents: {}", e)))
+    }
 }
 
 /// Search result included in Answer response
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct AnswerSearchResult {
     /// Result title
     pub title: Option<String>,
@@ -917,8 +2475,57 @@ pub struct AnswerSearchResult {
     pub length: Option<i32>,
 }
 
+impl AnswerSearchResult {
+    /// Convert to a [`SearchResult`], so answer sources can be handled like
+    /// DeepSearch results
+    ///
+    /// `title`, `url`, and `length` carry over unchanged, `snippet` becomes
+    /// both `content` and `description`, and `date` becomes
+    /// `publication_date`. Every other `SearchResult` field (`id`, `source`,
+    /// `source_type`, `data_type`, `price`, `image_url`, `doi`, `citation`,
+    /// `citation_count`, `authors`, and `relevance_score`) is `None`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use valyu::{SearchResult, ValyuClient};
+    /// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+    /// let response = client.ask("What is quantum computing?").await?;
+    /// let sources: Vec<SearchResult> = response
+    ///     .search_results
+    ///     .unwrap_or_default()
+    ///     .into_iter()
+    ///     .map(|result| result.into_search_result())
+    ///     .collect();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_search_result(self) -> SearchResult {
+        SearchResult {
+            id: None,
+            title: self.title,
+            url: self.url,
+            content: self.snippet.clone(),
+            description: self.snippet,
+            source: None,
+            source_type: None,
+            data_type: None,
+            length: self.length,
+            price: None,
+            image_url: None,
+            publication_date: self.date,
+            doi: None,
+            citation: None,
+            citation_count: None,
+            authors: None,
+            relevance_score: None,
+        }
+    }
+}
+
 /// Search metadata for Answer API
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct AnswerSearchMetadata {
     /// Search transaction ID
     pub search_tx_id: Option<String>,
@@ -932,6 +2539,7 @@ pub struct AnswerSearchMetadata {
 
 /// AI usage statistics
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct AiUsage {
     /// Input tokens used
     pub input_tokens: Option<i32>,
@@ -942,6 +2550,7 @@ pub struct AiUsage {
 
 /// Cost breakdown for Answer API
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct AnswerCost {
     /// Total cost in dollars
     pub total_dollars: Option<f64>,
@@ -956,20 +2565,53 @@ pub struct AnswerCost {
 // ========== DeepResearch API Types ==========
 
 /// Research mode for DeepResearch API
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum DeepResearchMode {
     /// Fast mode - quick lookups, simple questions (1-2 min)
     Fast,
-    /// Lite mode - moderate research depth (5-10 min)
-    Lite,
+    /// Standard mode - moderate research depth (5-10 min)
+    #[default]
+    Standard,
     /// Heavy mode - comprehensive analysis (15-90 min)
     Heavy,
+    /// Former name of [`Standard`](Self::Standard), still accepted by the API
+    #[deprecated(note = "use DeepResearchMode::Standard")]
+    Lite,
 }
 
-impl Default for DeepResearchMode {
-    fn default() -> Self {
-        DeepResearchMode::Lite
+impl DeepResearchMode {
+    /// Suggested maximum seconds to wait for a task in this mode
+    ///
+    /// Used by [`ValyuClient::deepresearch_run`](crate::ValyuClient::deepresearch_run);
+    /// pass it as `max_wait_secs` to
+    /// [`ValyuClient::deepresearch_wait`](crate::ValyuClient::deepresearch_wait).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepResearchMode;
+    ///
+    /// assert_eq!(DeepResearchMode::Fast.default_timeout_secs(), 120);
+    /// assert_eq!(DeepResearchMode::Heavy.default_timeout_secs(), 5400);
+    /// ```
+    #[allow(deprecated)]
+    pub fn default_timeout_secs(&self) -> u64 {
+        match self {
+            DeepResearchMode::Fast => 120,
+            DeepResearchMode::Standard | DeepResearchMode::Lite => 600,
+            DeepResearchMode::Heavy => 5400,
+        }
+    }
+
+    /// Suggested seconds between status checks for a task in this mode
+    #[allow(deprecated)]
+    pub fn default_poll_interval_secs(&self) -> u64 {
+        match self {
+            DeepResearchMode::Fast => 2,
+            DeepResearchMode::Standard | DeepResearchMode::Lite => 5,
+            DeepResearchMode::Heavy => 15,
+        }
     }
 }
 
@@ -989,6 +2631,18 @@ pub enum DeepResearchStatus {
     Cancelled,
 }
 
+impl DeepResearchStatus {
+    /// Whether the task has finished and its status will not change again
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            DeepResearchStatus::Completed
+                | DeepResearchStatus::Failed
+                | DeepResearchStatus::Cancelled
+        )
+    }
+}
+
 /// File attachment for DeepResearch
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeepResearchFileAttachment {
@@ -1004,64 +2658,516 @@ pub struct DeepResearchFileAttachment {
     pub context: Option<String>,
 }
 
-/// MCP server configuration for DeepResearch
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeepResearchMCPServerConfig {
-    /// MCP server URL
-    pub url: String,
-    /// Server name
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub name: Option<String>,
-    /// Custom tool prefix
-    #[serde(skip_serializing_if = "Option::is_none", rename = "toolPrefix")]
-    pub tool_prefix: Option<String>,
-    /// Authentication configuration
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub auth: Option<serde_json::Value>,
-    /// Allowed tools
-    #[serde(skip_serializing_if = "Option::is_none", rename = "allowedTools")]
-    pub allowed_tools: Option<Vec<String>>,
+impl DeepResearchFileAttachment {
+    /// Create an attachment from a local file
+    ///
+    /// The file is base64-encoded into a `data:` URL, and the MIME type is
+    /// inferred from the file extension (falling back to
+    /// `application/octet-stream`).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{DeepResearchCreateRequest, DeepResearchFileAttachment};
+    ///
+    /// let report = DeepResearchFileAttachment::from_path(
+    ///     "reports/q3.pdf",
+    ///     Some("Quarterly report to compare against".to_string()),
+    /// )?;
+    ///
+    /// let request = DeepResearchCreateRequest::new("Summarize the changes since Q2")
+    ///     .with_files(vec![report]);
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::Io`] if the file cannot be read.
+    pub fn from_path(path: impl AsRef<Path>, context: Option<String>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Ok(Self::from_bytes(
+            &bytes,
+            &filename,
+            media_type_for_path(path),
+            context,
+        ))
+    }
+
+    /// Create an attachment from in-memory content
+    ///
+    /// The bytes are base64-encoded into a `data:` URL with the given MIME
+    /// type, for content that never touches the filesystem (e.g. a generated
+    /// PDF).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepResearchFileAttachment;
+    ///
+    /// let notes = DeepResearchFileAttachment::from_bytes(b"hello", "notes.txt", "text/plain", None);
+    /// assert_eq!(notes.data, "data:text/plain;base64,aGVsbG8=");
+    /// ```
+    pub fn from_bytes(
+        bytes: &[u8],
+        filename: &str,
+        media_type: &str,
+        context: Option<String>,
+    ) -> Self {
+        Self {
+            data: format!(
+                "data:{};base64,{}",
+                media_type,
+                base64::engine::general_purpose::STANDARD.encode(bytes)
+            ),
+            filename: filename.to_string(),
+            media_type: media_type.to_string(),
+            context,
+        }
+    }
 }
 
-/// Search configuration for DeepResearch
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeepResearchSearchConfig {
-    /// Type of search: "all", "web", or "proprietary"
-    #[serde(skip_serializing_if = "Option::is_none", rename = "searchType")]
-    pub search_type: Option<String>,
-    /// Sources to include in search
-    #[serde(skip_serializing_if = "Option::is_none", rename = "includedSources")]
-    pub included_sources: Option<Vec<String>>,
+/// Guess a file's MIME type from its extension
+fn media_type_for_path(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+
+    match extension.as_deref() {
+        Some("pdf") => "application/pdf",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("webp") => "image/webp",
+        Some("svg") => "image/svg+xml",
+        Some("txt") => "text/plain",
+        Some("md" | "markdown") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("html" | "htm") => "text/html",
+        Some("json") => "application/json",
+        Some("xml") => "application/xml",
+        Some("doc") => "application/msword",
+        Some("docx") => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        Some("xls") => "application/vnd.ms-excel",
+        Some("xlsx") => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        Some("pptx") => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        _ => "application/octet-stream",
+    }
 }
 
-/// Request parameters for creating a DeepResearch task
+/// Maximum number of deliverables per DeepResearch task
+const MAX_DELIVERABLES: usize = 10;
+
+/// Maximum length of a deliverable description, in characters
+const MAX_DELIVERABLE_DESCRIPTION_CHARS: usize = 500;
+
+/// Maximum number of previous reports a DeepResearch task can build on
+const MAX_PREVIOUS_REPORTS: usize = 3;
+
+/// Maximum number of URLs per DeepResearch task
+const MAX_RESEARCH_URLS: usize = 10;
+
+/// Maximum number of file attachments per DeepResearch task
+const MAX_RESEARCH_FILES: usize = 10;
+
+/// Maximum number of MCP servers per DeepResearch task
+const MAX_MCP_SERVERS: usize = 5;
+
+/// Check that an optional list has at most `max` items
+fn check_max_items<T>(field: &str, items: Option<&[T]>, max: usize) -> Result<()> {
+    match items {
+        Some(items) if items.len() > max => Err(ValyuError::InvalidRequest(format!(
+            "{} accepts at most {} items, got {}",
+            field,
+            max,
+            items.len()
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check that a URL is well-formed and uses the https scheme
+fn is_https_url(url: &str) -> bool {
+    reqwest::Url::parse(url).is_ok_and(|url| url.scheme() == "https")
+}
+
+/// Report format produced by a DeepResearch task
+///
+/// `Json` asks for structured output matching a JSON schema and cannot be
+/// combined with the other formats.
 ///
 /// # Example
 ///
 /// ```
-/// use valyu::{DeepResearchCreateRequest, DeepResearchMode};
+/// use valyu::{DeepResearchCreateRequest, OutputFormat};
 ///
-/// let request = DeepResearchCreateRequest::new("What are the key differences between RAG and fine-tuning?")
-///     .with_mode(DeepResearchMode::Lite)
-///     .with_output_formats(vec!["markdown".to_string()]);
+/// let request = DeepResearchCreateRequest::new("AI research")
+///     .with_formats(vec![OutputFormat::Markdown, OutputFormat::Pdf]);
 /// ```
-#[derive(Debug, Clone, Serialize)]
-pub struct DeepResearchCreateRequest {
-    /// Research query or task description (required)
-    pub input: String,
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputFormat {
+    /// Markdown report
+    Markdown,
+    /// PDF report
+    Pdf,
+    /// Structured output matching the given JSON schema
+    Json(serde_json::Value),
+}
 
-    /// Research mode: "fast", "lite", or "heavy"
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub model: Option<DeepResearchMode>,
+impl From<OutputFormat> for serde_json::Value {
+    fn from(format: OutputFormat) -> Self {
+        match format {
+            OutputFormat::Markdown => serde_json::Value::String("markdown".to_string()),
+            OutputFormat::Pdf => serde_json::Value::String("pdf".to_string()),
+            OutputFormat::Json(schema) => schema,
+        }
+    }
+}
 
-    /// Output formats: ["markdown"], ["markdown", "pdf"], or JSON schema
-    #[serde(skip_serializing_if = "Option::is_none", rename = "outputFormats")]
-    pub output_formats: Option<Vec<serde_json::Value>>,
+/// File type of a DeepResearch [`Deliverable`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliverableType {
+    /// Comma-separated values
+    Csv,
+    /// Excel spreadsheet
+    Xlsx,
+    /// PowerPoint presentation
+    Pptx,
+    /// Word document
+    Docx,
+    /// PDF document
+    Pdf,
+}
 
-    /// Natural language strategy instructions
+/// Additional file generated from a DeepResearch task
+///
+/// # Example
+///
+/// ```
+/// use valyu::Deliverable;
+///
+/// let spreadsheet = Deliverable::xlsx("Funding rounds by company")
+///     .with_sheet_name("Funding")
+///     .with_include_headers(true);
+/// let deck = Deliverable::pptx("Summary for the board", 8);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Deliverable {
+    /// File type to generate
+    #[serde(rename = "type")]
+    pub deliverable_type: DeliverableType,
+    /// What the file should contain (max 500 characters)
+    pub description: String,
+    /// Column names for CSV and XLSX files
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub strategy: Option<String>,
-
+    pub columns: Option<Vec<String>>,
+    /// Whether CSV and XLSX files include a header row
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_headers: Option<bool>,
+    /// Sheet name for XLSX files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheet_name: Option<String>,
+    /// Number of slides for PPTX files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slides: Option<u32>,
+    /// Template to base the file on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+impl Deliverable {
+    /// Create a deliverable of the given type
+    pub fn new(deliverable_type: DeliverableType, description: impl Into<String>) -> Self {
+        Self {
+            deliverable_type,
+            description: description.into(),
+            columns: None,
+            include_headers: None,
+            sheet_name: None,
+            slides: None,
+            template: None,
+        }
+    }
+
+    /// Create a CSV deliverable
+    pub fn csv(description: impl Into<String>) -> Self {
+        Self::new(DeliverableType::Csv, description)
+    }
+
+    /// Create an Excel spreadsheet deliverable
+    pub fn xlsx(description: impl Into<String>) -> Self {
+        Self::new(DeliverableType::Xlsx, description)
+    }
+
+    /// Create a PowerPoint deliverable with the given number of slides
+    pub fn pptx(description: impl Into<String>, slides: u32) -> Self {
+        Self {
+            slides: Some(slides),
+            ..Self::new(DeliverableType::Pptx, description)
+        }
+    }
+
+    /// Create a Word document deliverable
+    pub fn docx(description: impl Into<String>) -> Self {
+        Self::new(DeliverableType::Docx, description)
+    }
+
+    /// Create a PDF deliverable
+    pub fn pdf(description: impl Into<String>) -> Self {
+        Self::new(DeliverableType::Pdf, description)
+    }
+
+    /// Set the column names for CSV and XLSX files
+    pub fn with_columns(mut self, columns: Vec<String>) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Set whether CSV and XLSX files include a header row
+    pub fn with_include_headers(mut self, include: bool) -> Self {
+        self.include_headers = Some(include);
+        self
+    }
+
+    /// Set the sheet name for XLSX files
+    pub fn with_sheet_name(mut self, name: impl Into<String>) -> Self {
+        self.sheet_name = Some(name.into());
+        self
+    }
+
+    /// Set a template to base the file on
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.template = Some(template.into());
+        self
+    }
+}
+
+/// MCP server configuration for DeepResearch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeepResearchMCPServerConfig {
+    /// MCP server URL
+    pub url: String,
+    /// Server name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Custom tool prefix
+    #[serde(skip_serializing_if = "Option::is_none", rename = "toolPrefix")]
+    pub tool_prefix: Option<String>,
+    /// Authentication configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<McpAuth>,
+    /// Allowed tools
+    #[serde(skip_serializing_if = "Option::is_none", rename = "allowedTools")]
+    pub allowed_tools: Option<Vec<String>>,
+}
+
+/// Authentication for a DeepResearch MCP server
+///
+/// Serializes to the `auth` object of the API's MCP server config:
+/// `{"type": "none"}`, `{"type": "bearer", "token": ...}`, or
+/// `{"type": "header", "headerName": ..., "headerValue": ...}`, matching the
+/// camelCase keys of the rest of the config. Any other configuration can be
+/// passed through as [`Custom`](Self::Custom), and unrecognized shapes
+/// deserialize to it instead of failing.
+///
+/// `Debug` output hides tokens and header values.
+///
+/// # Example
+///
+/// ```
+/// use valyu::{DeepResearchMCPServerConfig, McpAuth};
+///
+/// let server = DeepResearchMCPServerConfig {
+///     url: "https://mcp.example.com".to_string(),
+///     name: Some("internal-docs".to_string()),
+///     tool_prefix: None,
+///     auth: Some(McpAuth::Bearer("mcp-token".to_string())),
+///     allowed_tools: None,
+/// };
+/// ```
+#[derive(Clone, PartialEq)]
+pub enum McpAuth {
+    /// The server needs no authentication
+    None,
+    /// Send `Authorization: Bearer <token>`
+    Bearer(String),
+    /// Send the credential in a custom header
+    Header {
+        /// Header name, e.g. `X-Api-Key`
+        name: String,
+        /// Header value
+        value: String,
+    },
+    /// Any other auth configuration, sent as-is
+    Custom(serde_json::Value),
+}
+
+impl fmt::Debug for McpAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            McpAuth::None => f.write_str("None"),
+            McpAuth::Bearer(token) => f
+                .debug_tuple("Bearer")
+                .field(&redact_api_key(token))
+                .finish(),
+            McpAuth::Header { name, value } => f
+                .debug_struct("Header")
+                .field("name", name)
+                .field("value", &redact_api_key(value))
+                .finish(),
+            // May hold credentials in any field, so none are shown
+            McpAuth::Custom(_) => f.debug_tuple("Custom").field(&"***").finish(),
+        }
+    }
+}
+
+impl From<McpAuth> for serde_json::Value {
+    fn from(auth: McpAuth) -> Self {
+        match auth {
+            McpAuth::None => serde_json::json!({ "type": "none" }),
+            McpAuth::Bearer(token) => serde_json::json!({ "type": "bearer", "token": token }),
+            McpAuth::Header { name, value } => {
+                serde_json::json!({ "type": "header", "headerName": name, "headerValue": value })
+            }
+            McpAuth::Custom(value) => value,
+        }
+    }
+}
+
+impl From<serde_json::Value> for McpAuth {
+    /// Recognize the known auth shapes, keeping anything else as
+    /// [`Custom`](McpAuth::Custom)
+    fn from(value: serde_json::Value) -> Self {
+        let Some(object) = value.as_object() else {
+            return McpAuth::Custom(value);
+        };
+        let field = |key: &str| object.get(key).and_then(|v| v.as_str());
+
+        match (field("type"), object.len()) {
+            (Some("none"), 1) => McpAuth::None,
+            (Some("bearer"), 2) => match field("token") {
+                Some(token) => McpAuth::Bearer(token.to_string()),
+                None => McpAuth::Custom(value),
+            },
+            (Some("header"), 3) => match (field("headerName"), field("headerValue")) {
+                (Some(name), Some(header_value)) => McpAuth::Header {
+                    name: name.to_string(),
+                    value: header_value.to_string(),
+                },
+                _ => McpAuth::Custom(value),
+            },
+            _ => McpAuth::Custom(value),
+        }
+    }
+}
+
+impl Serialize for McpAuth {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serde_json::Value::from(self.clone()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for McpAuth {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde_json::Value::deserialize(deserializer).map(McpAuth::from)
+    }
+}
+
+/// Search configuration for DeepResearch
+///
+/// # Example
+///
+/// ```
+/// use valyu::{DeepResearchSearchConfig, SearchType};
+///
+/// let search = DeepResearchSearchConfig::new()
+///     .with_search_type_enum(SearchType::Web)
+///     .with_included_sources(vec!["arxiv.org".to_string()]);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeepResearchSearchConfig {
+    /// Type of search: "all", "web", or "proprietary" (see [`SearchType`])
+    #[serde(skip_serializing_if = "Option::is_none", rename = "searchType")]
+    pub search_type: Option<String>,
+    /// Sources to include in search
+    #[serde(skip_serializing_if = "Option::is_none", rename = "includedSources")]
+    pub included_sources: Option<Vec<String>>,
+}
+
+impl DeepResearchSearchConfig {
+    /// Create an empty search configuration, leaving every option to the API
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the search type ("all", "web", or "proprietary")
+    ///
+    /// Unknown values are rejected by
+    /// [`DeepResearchCreateRequest::validate`] before the request is sent.
+    /// Prefer [`with_search_type_enum`](Self::with_search_type_enum) to catch
+    /// typos at compile time.
+    pub fn with_search_type(mut self, search_type: impl Into<String>) -> Self {
+        self.search_type = Some(search_type.into());
+        self
+    }
+
+    /// Set the search type from a [`SearchType`]
+    pub fn with_search_type_enum(mut self, search_type: SearchType) -> Self {
+        self.search_type = Some(search_type.into());
+        self
+    }
+
+    /// Set specific sources to search within
+    pub fn with_included_sources(mut self, sources: Vec<String>) -> Self {
+        self.included_sources = Some(sources);
+        self
+    }
+}
+
+/// Request parameters for creating a DeepResearch task
+///
+/// # Example
+///
+/// ```
+/// use valyu::{DeepResearchCreateRequest, DeepResearchMode};
+///
+/// let request = DeepResearchCreateRequest::new("What are the key differences between RAG and fine-tuning?")
+///     .with_mode(DeepResearchMode::Standard)
+///     .with_output_formats(vec!["markdown".to_string()]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeepResearchCreateRequest {
+    /// Research query or task description (required)
+    pub input: String,
+
+    /// Research mode: "fast", "standard", or "heavy"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<DeepResearchMode>,
+
+    /// Output formats: ["markdown"], ["markdown", "pdf"], or JSON schema
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outputFormats")]
+    pub output_formats: Option<Vec<serde_json::Value>>,
+
+    /// Additional files to generate, as descriptions or [`Deliverable`] objects (max 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deliverables: Option<Vec<serde_json::Value>>,
+
+    /// Natural language strategy instructions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<String>,
+
     /// Search configuration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub search: Option<DeepResearchSearchConfig>,
@@ -1093,6 +3199,15 @@ pub struct DeepResearchCreateRequest {
     /// Custom metadata
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
+
+    /// Timeout for this request only, overriding the client's timeout
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
+
+    /// Sent as the `Idempotency-Key` header so retried creations don't start
+    /// duplicate tasks
+    #[serde(skip)]
+    pub idempotency_key: Option<String>,
 }
 
 impl DeepResearchCreateRequest {
@@ -1110,6 +3225,7 @@ impl DeepResearchCreateRequest {
             input: input.into(),
             model: None,
             output_formats: None,
+            deliverables: None,
             strategy: None,
             search: None,
             urls: None,
@@ -1119,6 +3235,8 @@ impl DeepResearchCreateRequest {
             previous_reports: None,
             webhook_url: None,
             metadata: None,
+            timeout: None,
+            idempotency_key: None,
         }
     }
 
@@ -1133,6 +3251,14 @@ impl DeepResearchCreateRequest {
     ///     .with_mode(DeepResearchMode::Heavy);
     /// ```
     pub fn with_mode(mut self, mode: DeepResearchMode) -> Self {
+        // The deprecated variant can also arrive through a variable or serde,
+        // which the compiler can't flag
+        #[cfg(feature = "tracing")]
+        #[allow(deprecated)]
+        if mode == DeepResearchMode::Lite {
+            tracing::warn!("DeepResearchMode::Lite is deprecated, use DeepResearchMode::Standard");
+        }
+
         self.model = Some(mode);
         self
     }
@@ -1152,6 +3278,64 @@ impl DeepResearchCreateRequest {
         self
     }
 
+    /// Set output formats using [`OutputFormat`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{DeepResearchCreateRequest, OutputFormat};
+    /// use serde_json::json;
+    ///
+    /// let report = DeepResearchCreateRequest::new("AI research")
+    ///     .with_formats(vec![OutputFormat::Markdown, OutputFormat::Pdf]);
+    ///
+    /// let structured = DeepResearchCreateRequest::new("AI research")
+    ///     .with_formats(vec![OutputFormat::Json(json!({
+    ///         "type": "object",
+    ///         "properties": { "summary": { "type": "string" } }
+    ///     }))]);
+    /// ```
+    pub fn with_formats(mut self, formats: Vec<OutputFormat>) -> Self {
+        self.output_formats = Some(formats.into_iter().map(serde_json::Value::from).collect());
+        self
+    }
+
+    /// Request additional files generated from the research
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::{DeepResearchCreateRequest, Deliverable};
+    ///
+    /// let request = DeepResearchCreateRequest::new("Compare GPU cloud pricing")
+    ///     .with_deliverables(vec![
+    ///         Deliverable::csv("Price per GPU hour by provider")
+    ///             .with_columns(vec!["provider".to_string(), "gpu".to_string(), "price".to_string()]),
+    ///         Deliverable::pptx("Executive summary deck", 5),
+    ///     ]);
+    /// ```
+    pub fn with_deliverables(mut self, deliverables: Vec<Deliverable>) -> Self {
+        self.deliverables = Some(
+            deliverables
+                .into_iter()
+                .map(|deliverable| {
+                    serde_json::to_value(deliverable).expect("Deliverable serializes to JSON")
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /// Request additional files using raw JSON values
+    ///
+    /// Each value is either a plain description string or a deliverable
+    /// object. Prefer [`with_deliverables`](Self::with_deliverables) unless
+    /// you need options this SDK doesn't model yet.
+    pub fn with_deliverables_raw(mut self, deliverables: Vec<serde_json::Value>) -> Self {
+        self.deliverables = Some(deliverables);
+        self
+    }
+
     /// Set a JSON schema for structured output
     ///
     /// # Example
@@ -1198,11 +3382,11 @@ impl DeepResearchCreateRequest {
     /// ```
     /// use valyu::{DeepResearchCreateRequest, DeepResearchSearchConfig};
     ///
-    /// let request = DeepResearchCreateRequest::new("AI research")
-    ///     .with_search(DeepResearchSearchConfig {
-    ///         search_type: Some("web".to_string()),
-    ///         included_sources: Some(vec!["arxiv.org".to_string()]),
-    ///     });
+    /// let request = DeepResearchCreateRequest::new("AI research").with_search(
+    ///     DeepResearchSearchConfig::new()
+    ///         .with_search_type("web")
+    ///         .with_included_sources(vec!["arxiv.org".to_string()]),
+    /// );
     /// ```
     pub fn with_search(mut self, search: DeepResearchSearchConfig) -> Self {
         self.search = Some(search);
@@ -1249,6 +3433,8 @@ impl DeepResearchCreateRequest {
     }
 
     /// Set webhook URL for completion notification
+    ///
+    /// The URL must use HTTPS; this is checked by [`validate`](Self::validate).
     pub fn with_webhook_url(mut self, url: impl Into<String>) -> Self {
         self.webhook_url = Some(url.into());
         self
@@ -1259,10 +3445,198 @@ impl DeepResearchCreateRequest {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Set custom metadata from any serializable value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::Serialize;
+    /// use valyu::DeepResearchCreateRequest;
+    ///
+    /// #[derive(Serialize)]
+    /// struct JobInfo {
+    ///     customer_id: u64,
+    ///     batch: String,
+    /// }
+    ///
+    /// let request = DeepResearchCreateRequest::new("AI research")
+    ///     .with_metadata_typed(&JobInfo { customer_id: 42, batch: "nightly".to_string() })?;
+    /// # Ok::<(), valyu::ValyuError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::ParseError`] if the value cannot be serialized to
+    /// JSON, e.g. a map with non-string keys.
+    pub fn with_metadata_typed<T: Serialize>(mut self, value: &T) -> Result<Self> {
+        let metadata = serde_json::to_value(value)
+            .map_err(|e| ValyuError::ParseError(format!("Failed to serialize metadata: {}", e)))?;
+        self.metadata = Some(metadata);
+        Ok(self)
+    }
+
+    /// Set a timeout for this request, overriding the client's timeout
+    ///
+    /// A request that exceeds it fails with [`ValyuError::Timeout`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepResearchCreateRequest;
+    /// use std::time::Duration;
+    ///
+    /// let request = DeepResearchCreateRequest::new("AI research")
+    ///     .with_timeout(Duration::from_secs(10));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the `Idempotency-Key` header sent when creating the task
+    ///
+    /// If a creation request is retried, e.g. after a timeout where the first
+    /// attempt actually reached the server, the API recognises the repeated
+    /// key and returns the task it already created instead of starting (and
+    /// billing) a second one. Use a fresh key for each distinct task.
+    ///
+    /// When the client has a [`RetryPolicy`](crate::RetryPolicy) with retries
+    /// enabled, a random key is generated for requests that don't set one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepResearchCreateRequest;
+    ///
+    /// let request = DeepResearchCreateRequest::new("AI research")
+    ///     .with_idempotency_key("report-2024-q3");
+    /// ```
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Check the request for values the API would reject
+    ///
+    /// This is called automatically by
+    /// [`ValyuClient::deepresearch_create`](crate::ValyuClient::deepresearch_create).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValyuError::InvalidRequest`] if:
+    /// - The search configuration has an unknown `search_type`
+    /// - More than 10 deliverables are requested
+    /// - A deliverable description is longer than 500 characters
+    /// - More than 3 previous reports, 10 URLs, 10 files, or 5 MCP servers
+    ///   are provided
+    /// - An MCP server `url` is not a valid `https://` URL, or its
+    ///   `allowed_tools` list is empty
+    /// - A JSON schema output format is combined with other output formats
+    /// - `webhook_url` is not a valid `https://` URL
+    /// - A structured output format is not a valid JSON Schema (with the
+    ///   `jsonschema` feature)
+    pub fn validate(&self) -> Result<()> {
+        if let Some(search) = &self.search {
+            validate_search_type(search.search_type.as_deref())?;
+        }
+
+        check_max_items(
+            "previous_reports",
+            self.previous_reports.as_deref(),
+            MAX_PREVIOUS_REPORTS,
+        )?;
+        check_max_items("urls", self.urls.as_deref(), MAX_RESEARCH_URLS)?;
+        check_max_items("files", self.files.as_deref(), MAX_RESEARCH_FILES)?;
+        check_max_items("mcp_servers", self.mcp_servers.as_deref(), MAX_MCP_SERVERS)?;
+        check_max_items(
+            "deliverables",
+            self.deliverables.as_deref(),
+            MAX_DELIVERABLES,
+        )?;
+
+        if let Some(formats) = &self.output_formats {
+            if formats.len() > 1 && formats.iter().any(|f| f.is_object()) {
+                return Err(ValyuError::InvalidRequest(
+                    "a JSON schema output format cannot be combined with other output formats"
+                        .to_string(),
+                ));
+            }
+        }
+
+        #[cfg(feature = "jsonschema")]
+        for schema in self
+            .output_formats
+            .iter()
+            .flatten()
+            .filter(|f| f.is_object())
+        {
+            check_json_schema("output_formats", schema)?;
+        }
+
+        for server in self.mcp_servers.iter().flatten() {
+            if !is_https_url(&server.url) {
+                return Err(ValyuError::InvalidRequest(format!(
+                    "MCP server url must be a valid https:// URL: {}",
+                    server.url
+                )));
+            }
+            if server
+                .allowed_tools
+                .as_ref()
+                .is_some_and(|tools| tools.is_empty())
+            {
+                return Err(ValyuError::InvalidRequest(format!(
+                    "MCP server allowed_tools is empty, which would allow no tools: {}",
+                    server.url
+                )));
+            }
+        }
+
+        if let Some(webhook_url) = &self.webhook_url {
+            if !is_https_url(webhook_url) {
+                return Err(ValyuError::InvalidRequest(format!(
+                    "webhook_url must be a valid https:// URL: {}",
+                    webhook_url
+                )));
+            }
+        }
+
+        if let Some(deliverables) = &self.deliverables {
+            for deliverable in deliverables {
+                let description = match deliverable {
+                    serde_json::Value::String(description) => Some(description.as_str()),
+                    other => other.get("description").and_then(|d| d.as_str()),
+                };
+                let chars = description.map_or(0, |d| d.chars().count());
+                if chars > MAX_DELIVERABLE_DESCRIPTION_CHARS {
+                    return Err(ValyuError::InvalidRequest(format!(
+                        "deliverable descriptions must be at most {} characters, got {}",
+                        MAX_DELIVERABLE_DESCRIPTION_CHARS, chars
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&str> for DeepResearchCreateRequest {
+    fn from(query: &str) -> Self {
+        Self::new(query)
+    }
+}
+
+impl From<String> for DeepResearchCreateRequest {
+    fn from(query: String) -> Self {
+        Self::new(query)
+    }
 }
 
 /// Response from creating a DeepResearch task
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepResearchCreateResponse {
     /// Whether the request was successful
     pub success: bool,
@@ -1293,10 +3667,17 @@ pub struct DeepResearchCreateResponse {
 
     /// Error message if failed
     pub error: Option<String>,
+
+    /// Rate-limit headers the response was received with, set by the client
+    ///
+    /// `None` when the API sent none of them.
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimitInfo>,
 }
 
 /// Progress information for a running task
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepResearchProgress {
     /// Current step number
     pub current_step: i32,
@@ -1306,6 +3687,7 @@ pub struct DeepResearchProgress {
 
 /// Source information from research
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepResearchSource {
     /// Source title
     pub title: String,
@@ -1331,8 +3713,44 @@ pub struct DeepResearchSource {
     pub word_count: Option<i32>,
 }
 
+/// Generation status of a [`DeliverableResult`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliverableStatus {
+    /// The file was generated and can be downloaded
+    Completed,
+    /// The file could not be generated
+    Failed,
+}
+
+/// File generated for a requested [`Deliverable`]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct DeliverableResult {
+    /// Deliverable identifier
+    pub id: Option<String>,
+    /// File type
+    #[serde(rename = "type")]
+    pub deliverable_type: Option<DeliverableType>,
+    /// Generation status
+    pub status: Option<DeliverableStatus>,
+    /// File title
+    pub title: Option<String>,
+    /// Download URL (when completed)
+    pub url: Option<String>,
+    /// S3 key
+    pub s3_key: Option<String>,
+    /// Number of data rows (CSV and XLSX)
+    pub row_count: Option<u64>,
+    /// Number of columns (CSV and XLSX)
+    pub column_count: Option<u64>,
+    /// Error message (when failed)
+    pub error: Option<String>,
+}
+
 /// Image metadata from research
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepResearchImage {
     /// Unique image identifier
     pub image_id: String,
@@ -1356,6 +3774,7 @@ pub struct DeepResearchImage {
 
 /// Usage and cost breakdown
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepResearchUsage {
     /// Search cost in dollars
     pub search_cost: f64,
@@ -1369,12 +3788,49 @@ pub struct DeepResearchUsage {
     pub total_cost: f64,
 }
 
-/// Response from getting task status
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct DeepResearchStatusResponse {
-    /// Whether the request was successful
-    pub success: bool,
-
+impl DeepResearchUsage {
+    /// Each cost component as a fraction of the total cost
+    ///
+    /// Returns `("search", _)`, `("contents", _)`, `("ai", _)`, and
+    /// `("compute", _)` in that order, with fractions between 0 and 1. All
+    /// fractions are 0 when the total cost is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use valyu::DeepResearchUsage;
+    ///
+    /// # fn print(usage: &DeepResearchUsage) {
+    /// for (component, fraction) in usage.breakdown_percentages() {
+    ///     println!("{}: {:.1}%", component, fraction * 100.0);
+    /// }
+    /// # }
+    /// ```
+    pub fn breakdown_percentages(&self) -> [(String, f64); 4] {
+        let fraction = |cost: f64| {
+            if self.total_cost > 0.0 {
+                cost / self.total_cost
+            } else {
+                0.0
+            }
+        };
+
+        [
+            ("search".to_string(), fraction(self.search_cost)),
+            ("contents".to_string(), fraction(self.contents_cost)),
+            ("ai".to_string(), fraction(self.ai_cost)),
+            ("compute".to_string(), fraction(self.compute_cost)),
+        ]
+    }
+}
+
+/// Response from getting task status
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct DeepResearchStatusResponse {
+    /// Whether the request was successful
+    pub success: bool,
+
     /// Unique task identifier
     pub deepresearch_id: Option<String>,
 
@@ -1399,8 +3855,9 @@ pub struct DeepResearchStatusResponse {
     /// Progress information (when running)
     pub progress: Option<DeepResearchProgress>,
 
-    /// Conversation messages
-    pub messages: Option<Vec<serde_json::Value>>,
+    /// Conversation messages, including the agent's intermediate steps
+    #[serde(default, deserialize_with = "deserialize_messages")]
+    pub messages: Option<Vec<DeepResearchMessage>>,
 
     /// Completion timestamp (Unix)
     pub completed_at: Option<i64>,
@@ -1420,15 +3877,225 @@ pub struct DeepResearchStatusResponse {
     /// Sources used in research
     pub sources: Option<Vec<DeepResearchSource>>,
 
+    /// Files generated for the requested deliverables
+    pub deliverables: Option<Vec<DeliverableResult>>,
+
     /// Usage and cost breakdown
     pub usage: Option<DeepResearchUsage>,
 
     /// Error message if failed
     pub error: Option<String>,
+
+    /// Rate-limit headers the response was received with, set by the client
+    ///
+    /// `None` when the API sent none of them.
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimitInfo>,
+
+    /// Fields returned by the API that this SDK doesn't model yet
+    ///
+    /// Always empty with the `strict-parsing` feature, which rejects unknown
+    /// fields instead.
+    #[cfg_attr(not(feature = "strict-parsing"), serde(flatten))]
+    #[cfg_attr(feature = "strict-parsing", serde(skip))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl DeepResearchStatusResponse {
+    /// The report text, when the output is markdown
+    ///
+    /// Returns `None` if the task has no output yet or produced JSON.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+    /// let result = client.deepresearch_wait("task-id", 5, 600).await?;
+    /// if let Some(report) = result.output_markdown() {
+    ///     println!("{}", report);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn output_markdown(&self) -> Option<&str> {
+        if self.output_type.as_deref() != Some("markdown") {
+            return None;
+        }
+        self.output.as_ref()?.as_str()
+    }
+
+    /// Deserialize JSON output into a custom type
+    ///
+    /// Use this when the task was created with
+    /// [`DeepResearchCreateRequest::with_structured_output`]. Returns `None` if
+    /// the task has no output yet or produced markdown.
+    ///
+    /// # Errors
+    ///
+    /// The inner result is [`ValyuError::ParseError`] if the output does not
+    /// match `T`.
+    pub fn output_json<T: DeserializeOwned>(&self) -> Option<Result<T>> {
+        if self.output_type.as_deref() != Some("json") {
+            return None;
+        }
+        let output = self.output.as_ref()?;
+
+        Some(
+            T::deserialize(output).map_err(|e| {
+                ValyuError::ParseError(format!("Failed to parse research output: {}", e))
+            }),
+        )
+    }
+
+    /// Write the output to `w`: markdown as-is, JSON output pretty-printed
+    ///
+    /// Nothing is written if the task has no output yet. Writing straight to
+    /// a file avoids copying large reports into another `String`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+    /// let result = client.deepresearch_wait("task-id", 5, 600).await?;
+    /// let mut file = std::io::BufWriter::new(std::fs::File::create("report.md")?);
+    /// result.write_output(&mut file)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_output(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        match &self.output {
+            Some(serde_json::Value::String(text)) => w.write_all(text.as_bytes()),
+            Some(output) => serde_json::to_writer_pretty(w, output).map_err(Into::into),
+            None => Ok(()),
+        }
+    }
+
+    /// Sources with duplicate URLs removed, keeping the first occurrence
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::ValyuClient;
+    ///
+    /// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+    /// let result = client.deepresearch_wait("task-id", 5, 600).await?;
+    /// for (i, source) in result.unique_sources().iter().enumerate() {
+    ///     println!("[{}] {} - {}", i + 1, source.title, source.url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unique_sources(&self) -> Vec<&DeepResearchSource> {
+        let mut seen = HashSet::new();
+        self.sources
+            .iter()
+            .flatten()
+            .filter(|source| seen.insert(source.url.as_str()))
+            .collect()
+    }
+
+    /// Group sources by their `source` type (e.g. `"web"`, `"pubmed"`)
+    ///
+    /// Sources without a type are grouped under `"unknown"`. Each group keeps
+    /// the order the sources were returned in.
+    pub fn sources_by_type(&self) -> HashMap<String, Vec<&DeepResearchSource>> {
+        let mut groups: HashMap<String, Vec<&DeepResearchSource>> = HashMap::new();
+        for source in self.sources.iter().flatten() {
+            let source_type = source.source.as_deref().unwrap_or("unknown");
+            groups
+                .entry(source_type.to_string())
+                .or_default()
+                .push(source);
+        }
+        groups
+    }
+
+    /// Messages written by the research agent (role `"assistant"`), in order
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use valyu::ValyuClient;
+    /// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+    /// let status = client.deepresearch_status("task-id").await?;
+    /// for message in status.assistant_messages() {
+    ///     println!("{}", message.content);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn assistant_messages(&self) -> Vec<&DeepResearchMessage> {
+        self.messages
+            .iter()
+            .flatten()
+            .filter(|message| message.role.as_deref() == Some("assistant"))
+            .collect()
+    }
+}
+
+/// Message in a DeepResearch task's conversation
+///
+/// Only `role` and `content` are modeled; messages in other shapes still
+/// parse, with missing fields left empty and everything else kept in `extra`.
+/// A message that isn't a JSON object (e.g. a bare string) is kept whole in
+/// `content`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
+pub struct DeepResearchMessage {
+    /// Author of the message, e.g. `"user"`, `"assistant"`, or `"tool"`
+    #[serde(default)]
+    pub role: Option<String>,
+
+    /// Message content: usually a string, but may be structured (e.g. a list
+    /// of content parts or a tool call)
+    #[serde(default)]
+    pub content: serde_json::Value,
+
+    /// Fields returned by the API that this SDK doesn't model yet
+    ///
+    /// Always empty with the `strict-parsing` feature, which rejects unknown
+    /// fields instead.
+    #[cfg_attr(not(feature = "strict-parsing"), serde(flatten))]
+    #[cfg_attr(feature = "strict-parsing", serde(skip))]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Deserialize a task's messages, keeping non-object entries as raw content
+/// so one odd message doesn't fail the whole status response
+fn deserialize_messages<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<Vec<DeepResearchMessage>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let Some(values) = Option::<Vec<serde_json::Value>>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    values
+        .into_iter()
+        .map(|value| {
+            if value.is_object() {
+                serde_json::from_value(value).map_err(serde::de::Error::custom)
+            } else {
+                Ok(DeepResearchMessage {
+                    role: None,
+                    content: value,
+                    extra: HashMap::new(),
+                })
+            }
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map(Some)
 }
 
 /// Response from listing tasks
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepResearchListResponse {
     /// Whether the request was successful
     pub success: bool,
@@ -1438,10 +4105,47 @@ pub struct DeepResearchListResponse {
 
     /// Error message if failed
     pub error: Option<String>,
+
+    /// Rate-limit headers the response was received with, set by the client
+    ///
+    /// `None` when the API sent none of them.
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimitInfo>,
+}
+
+impl DeepResearchListResponse {
+    /// Tasks with the given status, in the order they were listed
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use valyu::{DeepResearchStatus, ValyuClient};
+    ///
+    /// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+    /// let tasks = client.deepresearch_list("api-key-id", Some(50)).await?;
+    /// for task in tasks.filter_by_status(DeepResearchStatus::Running) {
+    ///     println!("{} is still running", task.deepresearch_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter_by_status(&self, status: DeepResearchStatus) -> Vec<&DeepResearchTaskListItem> {
+        self.data
+            .iter()
+            .flatten()
+            .filter(|task| task.status == status)
+            .collect()
+    }
+
+    /// Tasks that completed successfully
+    pub fn completed(&self) -> Vec<&DeepResearchTaskListItem> {
+        self.filter_by_status(DeepResearchStatus::Completed)
+    }
 }
 
 /// Minimal task info for list view
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepResearchTaskListItem {
     /// Unique task identifier
     pub deepresearch_id: String,
@@ -1457,6 +4161,7 @@ pub struct DeepResearchTaskListItem {
 
 /// Response from update/cancel/delete operations
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "strict-parsing", serde(deny_unknown_fields))]
 pub struct DeepResearchOperationResponse {
     /// Whether the request was successful
     pub success: bool,
@@ -1469,6 +4174,108 @@ pub struct DeepResearchOperationResponse {
 
     /// Error message if failed
     pub error: Option<String>,
+
+    /// Rate-limit headers the response was received with, set by the client
+    ///
+    /// `None` when the API sent none of them.
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimitInfo>,
+}
+
+// ========== Rate limits ==========
+
+/// Rate-limit state reported by the API's `X-RateLimit-*` headers
+///
+/// Attached to responses as `rate_limit`, so callers can slow down before
+/// hitting a 429.
+///
+/// # Example
+///
+/// ```no_run
+/// use valyu::ValyuClient;
+///
+/// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+/// let response = client.search("quantum computing").await?;
+/// if let Some(remaining) = response.rate_limit.and_then(|limit| limit.remaining) {
+///     if remaining < 5 {
+///         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests left in the current window (`X-RateLimit-Remaining`)
+    pub remaining: Option<u32>,
+    /// When the window resets, as a Unix timestamp in seconds (`X-RateLimit-Reset`)
+    pub reset_at: Option<i64>,
+    /// Requests allowed per window (`X-RateLimit-Limit`)
+    pub limit: Option<u32>,
+}
+
+// ========== Costs ==========
+
+/// A response or usage report with a cost in dollars
+///
+/// Implemented for the responses of all four APIs so spend can be totalled
+/// with [`sum_costs`].
+pub trait Cost {
+    /// Cost in dollars, if the API reported one
+    fn cost_dollars(&self) -> Option<f64>;
+}
+
+impl Cost for DeepSearchResponse {
+    fn cost_dollars(&self) -> Option<f64> {
+        self.total_deduction_dollars
+    }
+}
+
+impl Cost for ContentsResponse {
+    fn cost_dollars(&self) -> Option<f64> {
+        self.total_cost_dollars
+    }
+}
+
+impl Cost for AnswerResponse {
+    fn cost_dollars(&self) -> Option<f64> {
+        self.cost.as_ref()?.total_dollars
+    }
+}
+
+impl Cost for DeepResearchUsage {
+    fn cost_dollars(&self) -> Option<f64> {
+        Some(self.total_cost)
+    }
+}
+
+/// Total cost in dollars of several responses
+///
+/// Responses without a reported cost count as zero. Mix response types by
+/// passing `&dyn Cost` items.
+///
+/// # Example
+///
+/// ```no_run
+/// use valyu::{sum_costs, Cost, ValyuClient};
+///
+/// # async fn example(client: ValyuClient) -> Result<(), valyu::ValyuError> {
+/// let search = client.search("quantum computing").await?;
+/// let answer = client.ask("What is quantum computing?").await?;
+///
+/// let total = sum_costs([&search as &dyn Cost, &answer]);
+/// println!("Spent ${:.4}", total);
+/// # Ok(())
+/// # }
+/// ```
+pub fn sum_costs<'a, C>(items: impl IntoIterator<Item = &'a C>) -> f64
+where
+    C: Cost + ?Sized + 'a,
+{
+    items
+        .into_iter()
+        .filter_map(|item| item.cost_dollars())
+        .sum()
 }
 
 #[cfg(test)]
@@ -1497,4 +4304,1425 @@ mod tests {
         assert!(json.contains("test query"));
         assert!(json.contains("max_num_results"));
     }
+
+    #[test]
+    fn test_timeout_not_serialized() {
+        let request = DeepSearchRequest::new("test").with_timeout(Duration::from_secs(5));
+        assert_eq!(request.timeout, Some(Duration::from_secs(5)));
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("timeout").is_none());
+    }
+
+    #[test]
+    fn test_search_type_serialization() {
+        assert_eq!(serde_json::to_string(&SearchType::All).unwrap(), "\"all\"");
+        assert_eq!(serde_json::to_string(&SearchType::Web).unwrap(), "\"web\"");
+        assert_eq!(
+            serde_json::to_string(&SearchType::Proprietary).unwrap(),
+            "\"proprietary\""
+        );
+
+        let request = DeepSearchRequest::new("AI").with_search_type_enum(SearchType::Proprietary);
+        assert_eq!(request.search_type, Some("proprietary".to_string()));
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_response_length_enum() {
+        let request =
+            DeepSearchRequest::new("AI").with_response_length_enum(DeepSearchResponseLength::Max);
+        assert_eq!(request.response_length, Some("max".to_string()));
+        assert!(request.validate().is_ok());
+
+        let request = DeepSearchRequest::new("AI").with_response_length("meduim");
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_extract_effort_enum() {
+        let url = vec!["https://example.com".to_string()];
+        let request =
+            ContentsRequest::new(url.clone()).with_extract_effort_enum(ExtractEffort::High);
+        assert_eq!(request.extract_effort, Some("high".to_string()));
+        assert!(request.validate().is_ok());
+
+        let request = ContentsRequest::new(url).with_extract_effort("hgih");
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_extract_effort_serde_round_trip() {
+        for effort in [
+            ExtractEffort::Normal,
+            ExtractEffort::High,
+            ExtractEffort::Auto,
+        ] {
+            let json = serde_json::to_value(effort).unwrap();
+            assert_eq!(json, serde_json::json!(effort.as_str()));
+            assert_eq!(
+                serde_json::from_value::<ExtractEffort>(json).unwrap(),
+                effort
+            );
+            assert_eq!(effort.as_str().parse::<ExtractEffort>().unwrap(), effort);
+        }
+    }
+
+    #[test]
+    fn test_try_with_country_code() {
+        let request = DeepSearchRequest::new("AI")
+            .try_with_country_code("us")
+            .unwrap();
+        assert_eq!(request.country_code.as_deref(), Some("US"));
+
+        let request = AnswerRequest::new("AI")
+            .try_with_country_code("De")
+            .unwrap();
+        assert_eq!(request.country_code.as_deref(), Some("DE"));
+
+        for code in ["USA", "us1", "", "u", "ü1"] {
+            assert!(matches!(
+                DeepSearchRequest::new("AI").try_with_country_code(code),
+                Err(ValyuError::InvalidRequest(_))
+            ));
+            assert!(AnswerRequest::new("AI")
+                .try_with_country_code(code)
+                .is_err());
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deepresearch_mode_serde() {
+        assert_eq!(DeepResearchMode::default(), DeepResearchMode::Standard);
+        assert_eq!(
+            serde_json::to_value(DeepResearchMode::Standard).unwrap(),
+            serde_json::json!("standard")
+        );
+
+        // The deprecated mode still round-trips for backwards compatibility
+        assert_eq!(
+            serde_json::to_value(DeepResearchMode::Lite).unwrap(),
+            serde_json::json!("lite")
+        );
+        assert_eq!(
+            serde_json::from_value::<DeepResearchMode>(serde_json::json!("lite")).unwrap(),
+            DeepResearchMode::Lite
+        );
+    }
+
+    #[test]
+    fn test_file_attachment_from_path() {
+        let path =
+            std::env::temp_dir().join(format!("valyu-attachment-{}.PDF", std::process::id()));
+        std::fs::write(&path, b"hello").unwrap();
+
+        let attachment =
+            DeepResearchFileAttachment::from_path(&path, Some("context".to_string())).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(attachment.data, "data:application/pdf;base64,aGVsbG8=");
+        assert_eq!(attachment.media_type, "application/pdf");
+        assert_eq!(
+            attachment.filename,
+            path.file_name().unwrap().to_str().unwrap()
+        );
+        assert_eq!(attachment.context.as_deref(), Some("context"));
+
+        assert!(matches!(
+            DeepResearchFileAttachment::from_path(&path, None),
+            Err(ValyuError::Io(_))
+        ));
+        assert_eq!(
+            media_type_for_path(Path::new("notes")),
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_file_attachment_from_bytes() {
+        let attachment =
+            DeepResearchFileAttachment::from_bytes(b"hello", "report.pdf", "application/pdf", None);
+
+        assert_eq!(attachment.data, "data:application/pdf;base64,aGVsbG8=");
+        assert_eq!(attachment.filename, "report.pdf");
+        assert_eq!(attachment.media_type, "application/pdf");
+        assert_eq!(attachment.context, None);
+    }
+
+    #[test]
+    fn test_deliverables_serialize() {
+        let request = DeepResearchCreateRequest::new("AI").with_deliverables(vec![
+            Deliverable::csv("Prices").with_columns(vec!["gpu".to_string()]),
+            Deliverable::pptx("Deck", 5),
+        ]);
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["deliverables"],
+            serde_json::json!([
+                { "type": "csv", "description": "Prices", "columns": ["gpu"] },
+                { "type": "pptx", "description": "Deck", "slides": 5 },
+            ])
+        );
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_deliverables_validation() {
+        let request = DeepResearchCreateRequest::new("AI")
+            .with_deliverables(vec![Deliverable::csv("x".repeat(501))]);
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        let request = DeepResearchCreateRequest::new("AI")
+            .with_deliverables_raw(vec![serde_json::json!("x".repeat(501))]);
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        let request =
+            DeepResearchCreateRequest::new("AI")
+                .with_deliverables(vec![Deliverable::pdf("Report"); 11]);
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_requests_deserialize_from_config() {
+        let request: DeepSearchRequest = serde_json::from_value(serde_json::json!({
+            "query": "AI",
+            "max_num_results": 5,
+            "included_sources": ["arxiv.org"]
+        }))
+        .unwrap();
+        assert_eq!(request.query, "AI");
+        assert_eq!(request.max_num_results, Some(5));
+        assert_eq!(request.search_type, None);
+        assert!(request.validate().is_ok());
+
+        let request: ContentsRequest = serde_json::from_value(serde_json::json!({
+            "urls": ["https://example.com"],
+            "response_length": 5000,
+            "summary": true
+        }))
+        .unwrap();
+        assert!(matches!(
+            request.response_length,
+            Some(ResponseLength::Custom(5000))
+        ));
+        assert!(matches!(
+            request.summary,
+            Some(SummaryOption::Boolean(true))
+        ));
+
+        let request: AnswerRequest =
+            serde_json::from_value(serde_json::json!({ "query": "What is AI?" })).unwrap();
+        assert_eq!(request.timeout, None);
+
+        // Round-trips through the same camelCase keys sent to the API
+        let original = DeepResearchCreateRequest::new("AI")
+            .with_mode(DeepResearchMode::Heavy)
+            .with_previous_reports(vec!["task-1".to_string()])
+            .with_formats(vec![OutputFormat::Markdown]);
+        let request: DeepResearchCreateRequest =
+            serde_json::from_value(serde_json::to_value(&original).unwrap()).unwrap();
+        assert_eq!(request.model, Some(DeepResearchMode::Heavy));
+        assert_eq!(request.previous_reports, Some(vec!["task-1".to_string()]));
+        assert_eq!(
+            request.output_formats,
+            Some(vec![serde_json::json!("markdown")])
+        );
+        assert_eq!(request.idempotency_key, None);
+
+        assert!(serde_json::from_value::<DeepSearchRequest>(serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn test_search_result_citation() {
+        let result =
+            |value: serde_json::Value| -> SearchResult { serde_json::from_value(value).unwrap() };
+
+        let provided =
+            result(serde_json::json!({ "title": "Paper", "citation": "Doe, J. (2020). Paper." }));
+        assert_eq!(
+            provided.to_citation().as_deref(),
+            Some("Doe, J. (2020). Paper.")
+        );
+
+        let two_authors = result(serde_json::json!({
+            "title": "Paper.",
+            "authors": ["Doe, J.", "Roe, R."],
+            "publication_date": "2020",
+            "url": "https://example.com/paper"
+        }));
+        assert_eq!(
+            two_authors.to_citation().as_deref(),
+            Some("Doe, J. & Roe, R. (2020). Paper. https://example.com/paper")
+        );
+
+        let no_authors = result(serde_json::json!({
+            "title": "Paper",
+            "doi": "https://doi.org/10.1000/xyz",
+            "url": "https://example.com/paper"
+        }));
+        assert_eq!(
+            no_authors.to_citation().as_deref(),
+            Some("Paper. (n.d.). https://doi.org/10.1000/xyz")
+        );
+
+        assert_eq!(
+            result(serde_json::json!({ "url": "https://example.com" })).to_citation(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_counts_accept_numbers_and_strings() {
+        let search: DeepSearchResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "total_characters": "1200",
+            "results": [
+                { "length": 800, "citation_count": "12" },
+                { "length": " 400 ", "citation_count": null },
+                { "length": "" }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(search.total_characters, Some(1200));
+        let results = search.results.unwrap();
+        assert_eq!(results[0].length, Some(800));
+        assert_eq!(results[0].citation_count, Some(12));
+        assert_eq!(results[1].length, Some(400));
+        assert_eq!(results[1].citation_count, None);
+        assert_eq!(results[2].length, None);
+
+        let contents: ContentsResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "urls_requested": 2,
+            "urls_processed": "1",
+            "urls_failed": "1",
+            "total_characters": 500,
+            "results": [{ "characters": "500" }]
+        }))
+        .unwrap();
+        assert_eq!(contents.urls_requested, Some(2));
+        assert_eq!(contents.urls_processed, Some(1));
+        assert_eq!(contents.urls_failed, Some(1));
+        assert_eq!(contents.total_characters, Some(500));
+        assert_eq!(contents.results.unwrap()[0].characters, Some(500));
+
+        let error = serde_json::from_value::<SearchResult>(serde_json::json!({ "length": "long" }))
+            .unwrap_err();
+        assert!(error.to_string().contains("expected a number"));
+    }
+
+    #[test]
+    fn test_to_contents_request() {
+        let response = search_response(serde_json::json!([
+            { "url": "https://example.com/a" },
+            { "title": "No URL" },
+            { "url": "https://example.com/a" },
+            { "url": "https://example.com/b" },
+            { "url": "https://example.com/c" },
+        ]));
+        assert_eq!(
+            response.to_contents_request(2).urls,
+            ["https://example.com/a", "https://example.com/b"]
+        );
+        assert_eq!(response.to_contents_request(50).urls.len(), 3);
+
+        let many: Vec<_> = (0..15)
+            .map(|i| serde_json::json!({ "url": format!("https://example.com/{}", i) }))
+            .collect();
+        let request = search_response(serde_json::Value::Array(many)).to_contents_request(20);
+        assert_eq!(request.urls.len(), 10);
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn test_results_by_source_total() {
+        let by_source = |web, proprietary| ResultsBySource { web, proprietary };
+
+        assert_eq!(by_source(None, None).total(), 0);
+        assert_eq!(by_source(Some(3), None).total(), 3);
+        assert_eq!(by_source(None, Some(4)).total(), 4);
+        assert_eq!(by_source(Some(3), Some(4)).total(), 7);
+    }
+
+    #[test]
+    fn test_search_result_academic() {
+        let result =
+            |value: serde_json::Value| -> SearchResult { serde_json::from_value(value).unwrap() };
+
+        let with_doi = result(serde_json::json!({ "doi": "https://doi.org/10.1000/xyz" }));
+        assert!(with_doi.is_academic());
+        assert_eq!(
+            with_doi.doi_url().as_deref(),
+            Some("https://doi.org/10.1000/xyz")
+        );
+
+        let bare_doi = result(serde_json::json!({ "doi": " 10.1000/xyz " }));
+        assert_eq!(
+            bare_doi.doi_url().as_deref(),
+            Some("https://doi.org/10.1000/xyz")
+        );
+
+        let paper = result(serde_json::json!({ "source_type": "Research_Paper" }));
+        assert!(paper.is_academic());
+        assert_eq!(paper.doi_url(), None);
+
+        let website = result(serde_json::json!({ "source_type": "website", "doi": "" }));
+        assert!(!website.is_academic());
+        assert_eq!(website.doi_url(), None);
+    }
+
+    #[test]
+    fn test_metadata_typed() {
+        #[derive(Serialize)]
+        struct JobInfo {
+            customer_id: u64,
+        }
+
+        let request = DeepResearchCreateRequest::new("AI")
+            .with_metadata_typed(&JobInfo { customer_id: 42 })
+            .unwrap();
+        assert_eq!(
+            request.metadata,
+            Some(serde_json::json!({ "customer_id": 42 }))
+        );
+
+        let bad_keys = HashMap::from([((1, 2), "value")]);
+        assert!(matches!(
+            DeepResearchCreateRequest::new("AI").with_metadata_typed(&bad_keys),
+            Err(ValyuError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_answer_search_result_into_search_result() {
+        let result: AnswerSearchResult = serde_json::from_value(serde_json::json!({
+            "title": "Quantum computing",
+            "url": "https://example.com/qc",
+            "snippet": "Qubits can be in superposition",
+            "date": "2024-01-01",
+            "length": 1200
+        }))
+        .unwrap();
+
+        let result = result.into_search_result();
+        assert_eq!(result.title.as_deref(), Some("Quantum computing"));
+        assert_eq!(result.url.as_deref(), Some("https://example.com/qc"));
+        assert_eq!(
+            result.content.as_deref(),
+            Some("Qubits can be in superposition")
+        );
+        assert_eq!(result.description, result.content);
+        assert_eq!(result.publication_date.as_deref(), Some("2024-01-01"));
+        assert_eq!(result.length, Some(1200));
+        assert_eq!(result.relevance_score, None);
+    }
+
+    #[test]
+    fn test_output_formats() {
+        let schema = serde_json::json!({ "type": "object" });
+
+        let request = DeepResearchCreateRequest::new("AI")
+            .with_formats(vec![OutputFormat::Markdown, OutputFormat::Pdf]);
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["outputFormats"],
+            serde_json::json!(["markdown", "pdf"])
+        );
+        assert!(request.validate().is_ok());
+
+        let request = DeepResearchCreateRequest::new("AI")
+            .with_formats(vec![OutputFormat::Json(schema.clone())]);
+        assert_eq!(
+            serde_json::to_value(&request).unwrap()["outputFormats"],
+            serde_json::json!([schema])
+        );
+        assert!(request.validate().is_ok());
+
+        let request = DeepResearchCreateRequest::new("AI")
+            .with_formats(vec![OutputFormat::Markdown, OutputFormat::Json(schema)]);
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn test_deepresearch_list_limits() {
+        let ids = |n: usize| (0..n).map(|i| format!("id-{}", i)).collect::<Vec<_>>();
+        let file = || DeepResearchFileAttachment {
+            data: "data:text/plain;base64,".to_string(),
+            filename: "a.txt".to_string(),
+            media_type: "text/plain".to_string(),
+            context: None,
+        };
+        let server = || DeepResearchMCPServerConfig {
+            url: "https://mcp.example.com".to_string(),
+            name: None,
+            tool_prefix: None,
+            auth: None,
+            allowed_tools: None,
+        };
+
+        let at_limit = DeepResearchCreateRequest::new("AI")
+            .with_previous_reports(ids(3))
+            .with_urls(urls(10))
+            .with_files(vec![file(); 10])
+            .with_mcp_servers(vec![server(); 5])
+            .with_deliverables(vec![Deliverable::csv("x".repeat(500)); 10]);
+        assert!(at_limit.validate().is_ok());
+
+        let over_limit = [
+            at_limit.clone().with_previous_reports(ids(4)),
+            at_limit.clone().with_urls(urls(11)),
+            at_limit.clone().with_files(vec![file(); 11]),
+            at_limit.clone().with_mcp_servers(vec![server(); 6]),
+            at_limit
+                .clone()
+                .with_deliverables(vec![Deliverable::csv("x"); 11]),
+            at_limit.with_deliverables(vec![Deliverable::csv("x".repeat(501))]),
+        ];
+        for request in over_limit {
+            assert!(matches!(
+                request.validate(),
+                Err(ValyuError::InvalidRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_mcp_auth_shapes() {
+        let cases = [
+            (McpAuth::None, serde_json::json!({ "type": "none" })),
+            (
+                McpAuth::Bearer("secret".to_string()),
+                serde_json::json!({ "type": "bearer", "token": "secret" }),
+            ),
+            (
+                McpAuth::Header {
+                    name: "X-Api-Key".to_string(),
+                    value: "secret".to_string(),
+                },
+                serde_json::json!({
+                    "type": "header", "headerName": "X-Api-Key", "headerValue": "secret"
+                }),
+            ),
+        ];
+        for (auth, json) in cases {
+            assert_eq!(serde_json::to_value(&auth).unwrap(), json);
+            assert_eq!(serde_json::from_value::<McpAuth>(json).unwrap(), auth);
+        }
+
+        let oauth = serde_json::json!({ "type": "oauth", "client_id": "abc" });
+        let auth: McpAuth = serde_json::from_value(oauth.clone()).unwrap();
+        assert_eq!(auth, McpAuth::Custom(oauth.clone()));
+        assert_eq!(serde_json::to_value(&auth).unwrap(), oauth);
+
+        let bearer_with_extra =
+            serde_json::json!({ "type": "bearer", "token": "t", "scope": "read" });
+        assert!(matches!(
+            McpAuth::from(bearer_with_extra),
+            McpAuth::Custom(_)
+        ));
+    }
+
+    #[test]
+    fn test_mcp_auth_debug_hides_secrets() {
+        let server = DeepResearchMCPServerConfig {
+            url: "https://mcp.example.com".to_string(),
+            name: None,
+            tool_prefix: None,
+            auth: Some(McpAuth::Bearer("mcp-secret-token-1234".to_string())),
+            allowed_tools: None,
+        };
+        let debug = format!("{:?}", server);
+        assert!(!debug.contains("mcp-secret-token"), "{}", debug);
+        assert!(debug.contains("Bearer(\"...1234\")"), "{}", debug);
+
+        let header = McpAuth::Header {
+            name: "X-Api-Key".to_string(),
+            value: "header-secret-5678".to_string(),
+        };
+        assert_eq!(
+            format!("{:?}", header),
+            r#"Header { name: "X-Api-Key", value: "...5678" }"#
+        );
+
+        let custom = McpAuth::Custom(serde_json::json!({ "type": "oauth", "secret": "abc" }));
+        assert!(!format!("{:?}", custom).contains("abc"));
+    }
+
+    #[test]
+    fn test_webhook_url_validation() {
+        let request =
+            DeepResearchCreateRequest::new("AI").with_webhook_url("https://example.com/hook");
+        assert!(request.validate().is_ok());
+
+        for url in [
+            "http://example.com/hook",
+            "example.com/hook",
+            "https//example.com",
+            "",
+        ] {
+            let request = DeepResearchCreateRequest::new("AI").with_webhook_url(url);
+            assert!(matches!(
+                request.validate(),
+                Err(ValyuError::InvalidRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_mcp_server_validation() {
+        let server = |url: &str, allowed_tools: Option<Vec<String>>| DeepResearchMCPServerConfig {
+            url: url.to_string(),
+            name: None,
+            tool_prefix: None,
+            auth: None,
+            allowed_tools,
+        };
+
+        let request = DeepResearchCreateRequest::new("AI").with_mcp_servers(vec![
+            server("https://mcp.example.com", None),
+            server(
+                "https://mcp.example.com/v2",
+                Some(vec!["search".to_string()]),
+            ),
+        ]);
+        assert!(request.validate().is_ok());
+
+        for server in [
+            server("http://mcp.example.com", None),
+            server("mcp.example.com", None),
+            server("https://mcp.example.com", Some(Vec::new())),
+        ] {
+            let request = DeepResearchCreateRequest::new("AI").with_mcp_servers(vec![server]);
+            assert!(matches!(
+                request.validate(),
+                Err(ValyuError::InvalidRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_relevance_threshold_range() {
+        for threshold in [0.0, 0.5, 1.0] {
+            let request = DeepSearchRequest::new("AI").try_with_relevance_threshold(threshold);
+            assert!(request.unwrap().validate().is_ok());
+        }
+
+        for threshold in [-0.1, 1.1, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                DeepSearchRequest::new("AI").try_with_relevance_threshold(threshold),
+                Err(ValyuError::InvalidRequest(_))
+            ));
+
+            let request = DeepSearchRequest::new("AI").with_relevance_threshold(threshold);
+            assert!(matches!(
+                request.validate(),
+                Err(ValyuError::InvalidRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_custom_response_length_range() {
+        let urls = vec!["https://example.com".to_string()];
+
+        for chars in [1_000, 1_000_000] {
+            let request = ContentsRequest::new(urls.clone()).try_with_custom_response_length(chars);
+            assert!(request.unwrap().validate().is_ok());
+        }
+
+        for chars in [999, 1_000_001, 0, -1] {
+            assert!(matches!(
+                ContentsRequest::new(urls.clone()).try_with_custom_response_length(chars),
+                Err(ValyuError::InvalidRequest(_))
+            ));
+
+            let request = ContentsRequest::new(urls.clone()).with_custom_response_length(chars);
+            assert!(matches!(
+                request.validate(),
+                Err(ValyuError::InvalidRequest(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_contents_results_by_url() {
+        let response: ContentsResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "results": [
+                { "url": "https://example.com/a", "content": "a" },
+                { "content": "no url" },
+                { "url": "https://example.com/c", "content": "c" },
+            ]
+        }))
+        .unwrap();
+
+        let by_url = response.results_by_url();
+        assert_eq!(by_url.len(), 2);
+        assert_eq!(
+            by_url["https://example.com/c"].content,
+            Some(serde_json::json!("c"))
+        );
+
+        let requested = vec![
+            "https://example.com/a".to_string(),
+            "https://example.com/b".to_string(),
+            "https://example.com/c".to_string(),
+            "https://example.com/d".to_string(),
+        ];
+        assert_eq!(
+            response.failed_urls(&requested),
+            vec!["https://example.com/b", "https://example.com/d"]
+        );
+    }
+
+    #[test]
+    fn test_max_price_validation() {
+        let url = || vec!["https://example.com".to_string()];
+
+        assert!(DeepSearchRequest::new("AI")
+            .try_with_max_price(10.0)
+            .is_ok());
+        assert!(ContentsRequest::new(url())
+            .try_with_max_price_dollars(0.5)
+            .is_ok());
+        assert!(AnswerRequest::new("AI")
+            .try_with_data_max_price(20.0)
+            .is_ok());
+
+        for price in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(DeepSearchRequest::new("AI")
+                .try_with_max_price(price)
+                .is_err());
+            assert!(ContentsRequest::new(url())
+                .try_with_max_price_dollars(price)
+                .is_err());
+            assert!(AnswerRequest::new("AI")
+                .try_with_data_max_price(price)
+                .is_err());
+
+            let invalid = [
+                DeepSearchRequest::new("AI")
+                    .with_max_price(price)
+                    .validate(),
+                ContentsRequest::new(url())
+                    .with_max_price_dollars(price)
+                    .validate(),
+                AnswerRequest::new("AI")
+                    .with_data_max_price(price)
+                    .validate(),
+            ];
+            for result in invalid {
+                assert!(matches!(result, Err(ValyuError::InvalidRequest(_))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_search_type_validation() {
+        let request = DeepSearchRequest::new("AI").with_search_type("webb");
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        let request = AnswerRequest::new("AI").with_search_type("Web");
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        let request = DeepResearchCreateRequest::new("AI")
+            .with_search(DeepResearchSearchConfig::new().with_search_type("everything"));
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        let search = DeepResearchSearchConfig::new()
+            .with_search_type_enum(SearchType::Proprietary)
+            .with_included_sources(vec!["arxiv.org".to_string()]);
+        assert_eq!(
+            serde_json::to_value(&search).unwrap(),
+            serde_json::json!({ "searchType": "proprietary", "includedSources": ["arxiv.org"] })
+        );
+        assert!(DeepResearchCreateRequest::new("AI")
+            .with_search(search)
+            .validate()
+            .is_ok());
+
+        assert!(DeepSearchRequest::new("AI").validate().is_ok());
+        assert!(AnswerRequest::new("AI")
+            .with_search_type("all")
+            .validate()
+            .is_ok());
+    }
+
+    fn urls(count: usize) -> Vec<String> {
+        (0..count)
+            .map(|i| format!("https://example.com/article{}", i))
+            .collect()
+    }
+
+    #[test]
+    fn test_contents_url_count_validation() {
+        let empty = ContentsRequest::new(Vec::new());
+        assert!(matches!(
+            empty.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        assert!(ContentsRequest::new(urls(1)).validate().is_ok());
+        assert!(ContentsRequest::new(urls(10)).validate().is_ok());
+
+        let too_many = ContentsRequest::new(urls(11));
+        assert!(matches!(
+            too_many.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        let built = ContentsRequest::new(Vec::new())
+            .add_url("https://example.com/first")
+            .add_urls(urls(9));
+        assert_eq!(built.urls.len(), 10);
+        assert_eq!(built.urls[0], "https://example.com/first");
+        assert!(built.validate().is_ok());
+        assert!(built
+            .add_url("https://example.com/last")
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_contents_url_protocol_validation() {
+        let request = ContentsRequest::new(vec![
+            "https://example.com".to_string(),
+            "http://example.com".to_string(),
+        ]);
+        assert!(request.validate().is_ok());
+
+        let request = ContentsRequest::new(vec!["ftp://example.com/file".to_string()]);
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        let request = ContentsRequest::new(vec!["example.com".to_string()]);
+        assert!(matches!(
+            request.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+    }
+
+    fn content_result(content: Option<serde_json::Value>) -> ContentResult {
+        ContentResult {
+            title: None,
+            url: Some("https://example.com".to_string()),
+            content,
+            description: None,
+            publication_date: None,
+            images: None,
+            cost_dollars: None,
+            characters: None,
+            extra: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_content_as_string() {
+        let markdown = content_result(Some(serde_json::json!("# Heading")));
+        assert_eq!(markdown.content_as_string().as_deref(), Some("# Heading"));
+
+        let structured = content_result(Some(serde_json::json!({"title": "Hello"})));
+        assert_eq!(
+            structured.content_as_string().as_deref(),
+            Some("{\n  \"title\": \"Hello\"\n}")
+        );
+
+        assert_eq!(content_result(None).content_as_string(), None);
+    }
+
+    #[test]
+    fn test_content_as_typed() {
+        #[derive(Deserialize)]
+        struct Summary {
+            title: String,
+        }
+
+        let structured = content_result(Some(serde_json::json!({"title": "Hello"})));
+        let summary: Summary = structured.content_as().unwrap();
+        assert_eq!(summary.title, "Hello");
+
+        let markdown = content_result(Some(serde_json::json!("# Heading")));
+        assert!(matches!(
+            markdown.content_as::<Summary>(),
+            Err(ValyuError::ParseError(_))
+        ));
+        assert!(matches!(
+            content_result(None).content_as::<Summary>(),
+            Err(ValyuError::ParseError(_))
+        ));
+    }
+
+    fn answer_response(data_type: &str, contents: serde_json::Value) -> AnswerResponse {
+        serde_json::from_value(serde_json::json!({
+            "success": true,
+            "data_type": data_type,
+            "contents": contents,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_system_instructions_length() {
+        let at_limit = AnswerRequest::new("AI").with_system_instructions("a".repeat(2000));
+        assert!(at_limit.validate().is_ok());
+
+        let over_limit = AnswerRequest::new("AI").with_system_instructions("a".repeat(2001));
+        assert!(matches!(
+            over_limit.validate(),
+            Err(ValyuError::InvalidRequest(_))
+        ));
+
+        // Multi-byte characters count once each
+        let multibyte = AnswerRequest::new("AI").with_system_instructions("é".repeat(2000));
+        assert!(multibyte.validate().is_ok());
+    }
+
+    #[test]
+    fn test_answer_text() {
+        let response =
+            answer_response("unstructured", serde_json::json!("Quantum computing is..."));
+        assert_eq!(response.answer_text(), Some("Quantum computing is..."));
+
+        let response = answer_response("structured", serde_json::json!({"summary": "..."}));
+        assert_eq!(response.answer_text(), None);
+    }
+
+    #[test]
+    fn test_parse_contents() {
+        #[derive(Deserialize)]
+        struct Summary {
+            summary: String,
+        }
+
+        let response = answer_response("structured", serde_json::json!({"summary": "Short"}));
+        let parsed: Summary = response.parse_contents().unwrap();
+        assert_eq!(parsed.summary, "Short");
+
+        let response = answer_response("unstructured", serde_json::json!("plain text"));
+        assert!(matches!(
+            response.parse_contents::<Summary>(),
+            Err(ValyuError::ParseError(_))
+        ));
+    }
+
+    fn search_result(image_url: serde_json::Value) -> SearchResult {
+        serde_json::from_value(serde_json::json!({ "image_url": image_url })).unwrap()
+    }
+
+    #[test]
+    fn test_image_urls_numeric_keys() {
+        let result = search_result(serde_json::json!({
+            "10": "https://example.com/c.png",
+            "2": "https://example.com/b.png",
+            "0": "https://example.com/a.png",
+        }));
+
+        assert_eq!(
+            result.image_urls(),
+            vec![
+                "https://example.com/a.png",
+                "https://example.com/b.png",
+                "https://example.com/c.png",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_image_urls_flat_map() {
+        let result = search_result(serde_json::json!({
+            "main": "https://example.com/main.png",
+            "thumbnail": "https://example.com/thumb.png",
+        }));
+
+        assert_eq!(
+            result.image_urls(),
+            vec![
+                "https://example.com/main.png",
+                "https://example.com/thumb.png"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_image_urls_null() {
+        assert!(search_result(serde_json::Value::Null)
+            .image_urls()
+            .is_empty());
+        assert!(search_result(serde_json::json!({})).image_urls().is_empty());
+    }
+
+    fn search_response(results: serde_json::Value) -> DeepSearchResponse {
+        serde_json::from_value(serde_json::json!({ "success": true, "results": results })).unwrap()
+    }
+
+    #[test]
+    fn test_result_accessors() {
+        let response = search_response(serde_json::json!([{ "title": "a" }, { "title": "b" }]));
+        assert_eq!(response.result_count(), 2);
+        let titles: Vec<_> = response
+            .results_iter()
+            .filter_map(|r| r.title.as_deref())
+            .collect();
+        assert_eq!(titles, vec!["a", "b"]);
+        assert_eq!(response.into_results().len(), 2);
+
+        let empty = search_response(serde_json::Value::Null);
+        assert_eq!(empty.result_count(), 0);
+        assert_eq!(empty.results_iter().count(), 0);
+        assert!(empty.into_results().is_empty());
+    }
+
+    fn scored_response() -> DeepSearchResponse {
+        search_response(serde_json::json!([
+            { "title": "low", "relevance_score": 0.2 },
+            { "title": "none" },
+            { "title": "high", "relevance_score": 0.9 },
+            { "title": "mid", "relevance_score": 0.5 },
+        ]))
+    }
+
+    fn titles<'a>(results: impl IntoIterator<Item = &'a SearchResult>) -> Vec<&'a str> {
+        results
+            .into_iter()
+            .filter_map(|r| r.title.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_results_above() {
+        let response = scored_response();
+        assert_eq!(titles(response.results_above(0.5)), vec!["high", "mid"]);
+        assert_eq!(
+            titles(response.results_above(0.0)),
+            vec!["low", "high", "mid"]
+        );
+        assert!(response.results_above(0.95).is_empty());
+    }
+
+    #[test]
+    fn test_sort_by_relevance() {
+        let mut response = scored_response();
+        response.sort_by_relevance();
+        assert_eq!(
+            titles(response.results_iter()),
+            vec!["high", "mid", "low", "none"]
+        );
+
+        let mut empty = search_response(serde_json::Value::Null);
+        empty.sort_by_relevance();
+        assert!(empty.results.is_none());
+    }
+
+    #[cfg(not(feature = "strict-parsing"))]
+    #[test]
+    fn test_unknown_fields_are_preserved() {
+        let json = serde_json::json!({
+            "success": true,
+            "results": [{ "title": "a" }],
+            "new_field": { "nested": true },
+        });
+
+        let response: DeepSearchResponse = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            response.extra["new_field"],
+            serde_json::json!({ "nested": true })
+        );
+        assert!(!response.extra.contains_key("success"));
+
+        let round_trip = serde_json::to_value(&response).unwrap();
+        assert_eq!(round_trip["new_field"], json["new_field"]);
+
+        let status: DeepResearchStatusResponse =
+            serde_json::from_value(serde_json::json!({ "success": true, "eta": 30 })).unwrap();
+        assert_eq!(status.extra["eta"], 30);
+    }
+
+    #[cfg(feature = "jsonschema")]
+    #[test]
+    fn test_structured_output_schema_validation() {
+        let valid = serde_json::json!({
+            "type": "object",
+            "properties": { "summary": { "type": "string" } },
+            "required": ["summary"]
+        });
+        assert!(AnswerRequest::new("q")
+            .with_structured_output(valid.clone())
+            .validate()
+            .is_ok());
+        assert!(DeepResearchCreateRequest::new("q")
+            .with_structured_output(valid)
+            .validate()
+            .is_ok());
+
+        let invalid = serde_json::json!({
+            "type": "object",
+            "properties": { "summary": { "type": "strng" } }
+        });
+        match AnswerRequest::new("q")
+            .with_structured_output(invalid.clone())
+            .validate()
+        {
+            Err(ValyuError::InvalidRequest(message)) => {
+                assert!(message.contains("structured_output"));
+                assert!(message.contains("/properties/summary/type"));
+            }
+            other => panic!("expected InvalidRequest, got {:?}", other),
+        }
+        assert!(DeepResearchCreateRequest::new("q")
+            .with_structured_output(invalid.clone())
+            .validate()
+            .is_err());
+        assert!(
+            ContentsRequest::new(vec!["https://example.com".to_string()])
+                .with_summary_schema(invalid)
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_summary_schema_for() {
+        #[derive(Deserialize, schemars::JsonSchema)]
+        struct Article {
+            title: String,
+        }
+
+        let request = ContentsRequest::new(vec!["https://example.com".to_string()])
+            .with_summary_schema_for::<Article>();
+        let schema = match request.summary {
+            Some(SummaryOption::Schema(schema)) => schema,
+            other => panic!("expected a schema, got {:?}", other),
+        };
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["properties"]["title"]["type"], "string");
+        assert_eq!(schema["required"], serde_json::json!(["title"]));
+
+        let result: ContentResult =
+            serde_json::from_value(serde_json::json!({ "content": { "title": "Hello" } })).unwrap();
+        assert_eq!(result.content_as::<Article>().unwrap().title, "Hello");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_mode_defaults() {
+        assert_eq!(DeepResearchMode::Fast.default_timeout_secs(), 120);
+        assert_eq!(DeepResearchMode::Standard.default_timeout_secs(), 600);
+        assert_eq!(DeepResearchMode::Heavy.default_timeout_secs(), 5400);
+        assert_eq!(
+            DeepResearchMode::Lite.default_poll_interval_secs(),
+            DeepResearchMode::Standard.default_poll_interval_secs()
+        );
+    }
+
+    #[test]
+    fn test_deepresearch_sources() {
+        let status: DeepResearchStatusResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "sources": [
+                { "title": "A", "url": "https://a.com", "source": "web" },
+                { "title": "B", "url": "https://b.com", "source": "pubmed" },
+                { "title": "A again", "url": "https://a.com", "source": "web" },
+                { "title": "C", "url": "https://c.com" }
+            ]
+        }))
+        .unwrap();
+
+        let unique: Vec<&str> = status
+            .unique_sources()
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect();
+        assert_eq!(unique, ["A", "B", "C"]);
+
+        let by_type = status.sources_by_type();
+        assert_eq!(by_type["web"].len(), 2);
+        assert_eq!(by_type["pubmed"][0].title, "B");
+        assert_eq!(by_type["unknown"][0].title, "C");
+    }
+
+    #[test]
+    fn test_deepresearch_messages() {
+        let status: DeepResearchStatusResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "messages": [
+                { "role": "user", "content": "Research AI" },
+                { "role": "assistant", "content": "Searching arXiv" },
+                { "role": "tool", "content": [{ "type": "tool_result" }] },
+                { "role": "assistant", "content": "Writing report" }
+            ]
+        }))
+        .unwrap();
+
+        let steps: Vec<&serde_json::Value> = status
+            .assistant_messages()
+            .iter()
+            .map(|m| &m.content)
+            .collect();
+        assert_eq!(steps, ["Searching arXiv", "Writing report"]);
+    }
+
+    #[cfg(not(feature = "strict-parsing"))]
+    #[test]
+    fn test_deepresearch_message_unknown_shape() {
+        let message: DeepResearchMessage =
+            serde_json::from_value(serde_json::json!({ "type": "reasoning", "text": "Thinking" }))
+                .unwrap();
+        assert_eq!(message.role, None);
+        assert!(message.content.is_null());
+        assert_eq!(message.extra["text"], "Thinking");
+    }
+
+    #[test]
+    fn test_deepresearch_messages_lenient() {
+        let status: DeepResearchStatusResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "messages": [
+                { "role": null, "content": "No author" },
+                "Bare string message",
+                { "role": "assistant", "content": "Done" }
+            ]
+        }))
+        .unwrap();
+
+        let messages = status.messages.as_deref().unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0].role, None);
+        assert_eq!(messages[0].content, "No author");
+        assert_eq!(messages[1].role, None);
+        assert_eq!(messages[1].content, "Bare string message");
+        assert_eq!(status.assistant_messages().len(), 1);
+    }
+
+    #[test]
+    fn test_source_conversions() {
+        assert_eq!(
+            Source::Domain("https://www.Nature.com/articles".to_string()).to_api_string(),
+            "www.nature.com"
+        );
+        assert_eq!(
+            Source::from("https://arxiv.org/abs/"),
+            Source::Url("https://arxiv.org/abs/".to_string())
+        );
+        assert_eq!(
+            Source::from("valyu/valyu-arxiv"),
+            Source::Dataset("valyu/valyu-arxiv".to_string())
+        );
+        assert_eq!(
+            Source::from("arxiv.org"),
+            Source::Domain("arxiv.org".to_string())
+        );
+        assert_eq!(
+            Source::from("arxiv.org/abs"),
+            Source::Domain("arxiv.org/abs".to_string())
+        );
+
+        let request = DeepSearchRequest::new("q")
+            .with_included_sources(vec!["nature.com".to_string()])
+            .with_included_source(Source::Domain("HTTPS://ArXiv.org".to_string()))
+            .with_excluded_source("valyu/valyu-pubmed");
+        assert_eq!(
+            request.included_sources.unwrap(),
+            ["nature.com", "arxiv.org"]
+        );
+        assert_eq!(request.excluded_sources.unwrap(), ["valyu/valyu-pubmed"]);
+    }
+
+    #[test]
+    fn test_requests_from_strings() {
+        fn query(request: impl Into<DeepSearchRequest>) -> String {
+            request.into().query
+        }
+        assert_eq!(query("a"), "a");
+        assert_eq!(query(String::from("b")), "b");
+        let request = DeepSearchRequest::new("c");
+        assert_eq!(query(&request), "c");
+        assert_eq!(request.query, "c");
+        assert_eq!(AnswerRequest::from("d").query, "d");
+        assert_eq!(
+            DeepResearchCreateRequest::from(String::from("e")).input,
+            "e"
+        );
+    }
+
+    #[cfg(feature = "strict-parsing")]
+    #[test]
+    fn test_strict_parsing_rejects_unknown_fields() {
+        let known = serde_json::json!({ "success": true, "results": [{ "title": "a" }] });
+        assert!(serde_json::from_value::<DeepSearchResponse>(known).is_ok());
+
+        let drifted =
+            serde_json::json!({ "success": true, "results": [{ "title": "a", "score": 1 }] });
+        assert!(serde_json::from_value::<DeepSearchResponse>(drifted).is_err());
+
+        let drifted = serde_json::json!({ "success": true, "eta": 30 });
+        assert!(serde_json::from_value::<DeepResearchStatusResponse>(drifted).is_err());
+    }
+
+    #[test]
+    fn test_sum_costs() {
+        let searches: Vec<DeepSearchResponse> = [Some(0.5), None, Some(0.25)]
+            .into_iter()
+            .map(|cost| {
+                serde_json::from_value(
+                    serde_json::json!({ "success": true, "total_deduction_dollars": cost }),
+                )
+                .unwrap()
+            })
+            .collect();
+        assert_eq!(sum_costs(&searches), 0.75);
+
+        let answer = answer_response("unstructured", serde_json::json!("text"));
+        let usage = DeepResearchUsage {
+            search_cost: 0.0,
+            contents_cost: 0.0,
+            ai_cost: 0.0,
+            compute_cost: 0.0,
+            total_cost: 1.0,
+        };
+        assert_eq!(answer.cost_dollars(), None);
+        assert_eq!(sum_costs([&searches[0] as &dyn Cost, &answer, &usage]), 1.5);
+    }
+
+    #[test]
+    fn test_usage_breakdown_percentages() {
+        let usage = DeepResearchUsage {
+            search_cost: 0.5,
+            contents_cost: 0.25,
+            ai_cost: 0.2,
+            compute_cost: 0.05,
+            total_cost: 1.0,
+        };
+        assert_eq!(
+            usage.breakdown_percentages(),
+            [
+                ("search".to_string(), 0.5),
+                ("contents".to_string(), 0.25),
+                ("ai".to_string(), 0.2),
+                ("compute".to_string(), 0.05),
+            ]
+        );
+
+        let free = DeepResearchUsage {
+            total_cost: 0.0,
+            ..usage
+        };
+        assert!(free
+            .breakdown_percentages()
+            .iter()
+            .all(|(_, fraction)| *fraction == 0.0));
+    }
+
+    #[test]
+    fn test_answer_is_structured() {
+        assert!(answer_response("structured", serde_json::json!({})).is_structured());
+        assert!(!answer_response("unstructured", serde_json::json!("text")).is_structured());
+    }
+
+    #[test]
+    fn test_list_filter_by_status() {
+        let list: DeepResearchListResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "data": [
+                { "deepresearch_id": "a", "query": "q", "status": "completed", "created_at": 1 },
+                { "deepresearch_id": "b", "query": "q", "status": "running", "created_at": 2 },
+                { "deepresearch_id": "c", "query": "q", "status": "completed", "created_at": 3 }
+            ]
+        }))
+        .unwrap();
+
+        let ids: Vec<&str> = list
+            .completed()
+            .iter()
+            .map(|t| t.deepresearch_id.as_str())
+            .collect();
+        assert_eq!(ids, ["a", "c"]);
+        assert_eq!(list.filter_by_status(DeepResearchStatus::Running).len(), 1);
+        assert!(list.filter_by_status(DeepResearchStatus::Failed).is_empty());
+    }
+
+    #[test]
+    fn test_deepresearch_output_accessors() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Report {
+            title: String,
+        }
+
+        let markdown: DeepResearchStatusResponse = serde_json::from_value(serde_json::json!({
+            "success": true, "output_type": "markdown", "output": "# Report"
+        }))
+        .unwrap();
+        assert_eq!(markdown.output_markdown(), Some("# Report"));
+        assert!(markdown.output_json::<Report>().is_none());
+
+        let json: DeepResearchStatusResponse = serde_json::from_value(serde_json::json!({
+            "success": true, "output_type": "json", "output": { "title": "AI" }
+        }))
+        .unwrap();
+        assert_eq!(json.output_markdown(), None);
+        assert_eq!(
+            json.output_json::<Report>().unwrap().unwrap(),
+            Report {
+                title: "AI".to_string()
+            }
+        );
+        assert!(json.output_json::<Vec<String>>().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_deepresearch_write_output() {
+        let status = |value: serde_json::Value| -> DeepResearchStatusResponse {
+            serde_json::from_value(value).unwrap()
+        };
+
+        let mut written = Vec::new();
+        status(serde_json::json!({ "success": true, "output": "# Report" }))
+            .write_output(&mut written)
+            .unwrap();
+        assert_eq!(written, b"# Report");
+
+        let mut written = Vec::new();
+        status(serde_json::json!({ "success": true, "output": { "title": "AI" } }))
+            .write_output(&mut written)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            "{\n  \"title\": \"AI\"\n}"
+        );
+
+        let mut written = Vec::new();
+        status(serde_json::json!({ "success": true }))
+            .write_output(&mut written)
+            .unwrap();
+        assert!(written.is_empty());
+    }
 }